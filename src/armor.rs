@@ -0,0 +1,288 @@
+#![cfg(feature = "armor")]
+//! [RFC 4880](https://datatracker.ietf.org/doc/html/rfc4880#section-6.2) OpenPGP ascii armor
+//!
+//! ```text
+//! -----BEGIN PGP MESSAGE-----
+//! Comment: hello world
+//!
+//! rBJiAGrVoSVoZWxsbywgd29ybGQ=
+//! =9i7M
+//! -----END PGP MESSAGE-----
+//! ```
+//!
+//! the armor looks like pem, but the begin line may be followed by "Key: Value" headers
+//! and a blank line, and the base64 body is followed by a checksum line,
+//! a '=' then the base64 of the body's 24 bits crc
+use crate::makura_alloc::{String, Vec, format};
+
+use crate::{BASE64, DecodeError, Decoder, Encoder};
+
+/// the begin line of a clear signed text, which is followed by the text's signature block
+const CLEARSIGN_BEGIN: &str = "-----BEGIN PGP SIGNED MESSAGE-----";
+
+/// the count of chars that a full body line holds
+const LINE_LEN: usize = 64;
+
+/// the initial value of the crc
+const CRC24_INIT: u32 = 0xb704ce;
+
+/// the crc's generator polynomial
+const CRC24_POLY: u32 = 0x1864cfb;
+
+/// the kind of the armored data, which is written in the begin and end lines
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ArmorKind {
+    /// "PGP MESSAGE", an encrypted, signed or compressed message
+    Message,
+    /// "PGP PUBLIC KEY BLOCK"
+    PublicKey,
+    /// "PGP PRIVATE KEY BLOCK"
+    PrivateKey,
+    /// "PGP SIGNATURE", a detached signature
+    Signature,
+    /// "PGP MESSAGE, PART X/Y", a part of a message that was split into many armored blocks,
+    /// "PGP MESSAGE, PART X" when the count of parts is not known
+    MessagePart { part: u32, total: Option<u32> },
+}
+
+impl ArmorKind {
+    /// returns the label of the begin and end lines, e.g., "PGP PUBLIC KEY BLOCK"
+    pub fn label(&self) -> String {
+        match self {
+            Self::Message => "PGP MESSAGE".into(),
+            Self::PublicKey => "PGP PUBLIC KEY BLOCK".into(),
+            Self::PrivateKey => "PGP PRIVATE KEY BLOCK".into(),
+            Self::Signature => "PGP SIGNATURE".into(),
+            Self::MessagePart {
+                part,
+                total: Some(total),
+            } => format!("PGP MESSAGE, PART {part}/{total}"),
+            Self::MessagePart { part, total: None } => format!("PGP MESSAGE, PART {part}"),
+        }
+    }
+
+    fn from_label(label: &str) -> Option<Self> {
+        match label {
+            "PGP MESSAGE" => Some(Self::Message),
+            "PGP PUBLIC KEY BLOCK" => Some(Self::PublicKey),
+            "PGP PRIVATE KEY BLOCK" => Some(Self::PrivateKey),
+            "PGP SIGNATURE" => Some(Self::Signature),
+            _ => {
+                let part = label.strip_prefix("PGP MESSAGE, PART ")?;
+                let (part, total) = match part.split_once('/') {
+                    Some((part, total)) => (part, Some(total.parse().ok()?)),
+                    None => (part, None),
+                };
+
+                Some(Self::MessagePart {
+                    part: part.parse().ok()?,
+                    total,
+                })
+            }
+        }
+    }
+}
+
+/// an armored block's kind, headers and data
+#[derive(Debug, PartialEq, Clone)]
+pub struct Armor {
+    pub kind: ArmorKind,
+    /// the armor headers in their order, e.g., ("Version", "GnuPG v2")
+    pub headers: Vec<(String, String)>,
+    /// the decoded data
+    pub data: Vec<u8>,
+}
+
+impl Armor {
+    /// creates a new armored block from its kind and data, without any headers
+    pub fn new(kind: ArmorKind, data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            kind,
+            headers: Vec::new(),
+            data: data.into(),
+        }
+    }
+
+    /// adds a header, e.g., `.header("Comment", "signed release")`
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+
+        self
+    }
+
+    /// returns the value of the Version header, if there is one
+    pub fn version(&self) -> Option<&str> {
+        self.find_header("Version")
+    }
+
+    /// returns the value of the first Comment header, if there is one
+    pub fn comment(&self) -> Option<&str> {
+        self.find_header("Comment")
+    }
+
+    fn find_header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// errors that can occur while decoding an armored block
+#[derive(Debug)]
+pub enum ArmorError {
+    /// no "-----BEGIN PGP ...-----" line was found
+    MissingBegin,
+    /// the begin line's label is not one of the armor kinds
+    /// .0 corresponds to the label
+    UnknownKind(String),
+    /// a header line is not in the "Key: Value" form
+    /// .0 corresponds to the bad header line
+    BadHeader(String),
+    /// the body was not followed by an end line with the same label as the begin line
+    MissingEnd,
+    /// the body or the checksum failed to decode
+    DecodeError(DecodeError),
+    /// the checksum line doesn't match the crc of the decoded data
+    BadChecksum { expected: u32, found: u32 },
+}
+
+impl core::fmt::Display for ArmorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MissingBegin => write!(f, "no armor begin line"),
+            Self::UnknownKind(label) => write!(f, "unknown armor kind {label:?}"),
+            Self::BadHeader(line) => write!(f, "bad armor header {line:?}"),
+            Self::MissingEnd => write!(f, "no armor end line"),
+            Self::DecodeError(err) => write!(f, "{err}"),
+            Self::BadChecksum { expected, found } => {
+                write!(f, "armor checksum {expected:06X} doesn't match {found:06X}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ArmorError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::DecodeError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// computes the 24 bits crc of the armor's checksum line
+///
+/// ```
+/// assert_eq!(makura::armor::crc24(b"123456789"), 0x21cf02);
+/// ```
+pub fn crc24(data: &[u8]) -> u32 {
+    data.iter().fold(CRC24_INIT, |crc, b| {
+        (0..8).fold(crc ^ (*b as u32) << 16, |crc, _| {
+            let crc = crc << 1;
+            if crc & 0x1000000 != 0 {
+                crc ^ CRC24_POLY
+            } else {
+                crc
+            }
+        })
+    }) & 0xffffff
+}
+
+/// produces the whole armored block: the begin line, the headers and the blank line,
+/// the body lines of 64 chars, the checksum line and the end line
+#[cfg(feature = "encoding")]
+pub fn encode(armor: &Armor) -> String {
+    let label = armor.kind.label();
+    let body = Encoder::base64().encode_bytes(&armor.data);
+
+    let mut encoded = format!("-----BEGIN {label}-----\n");
+    armor.headers.iter().for_each(|(name, value)| {
+        encoded.push_str(name);
+        encoded.push_str(": ");
+        encoded.push_str(value);
+        encoded.push('\n');
+    });
+    encoded.push('\n');
+    // the base64 chars are all ascii, so the lines can be split at any byte
+    body.as_bytes().chunks(LINE_LEN).for_each(|line| {
+        encoded.extend(line.iter().map(|b| *b as char));
+        encoded.push('\n');
+    });
+    encoded.push('=');
+    encoded.push_str(&Encoder::base64().encode_bytes(&crc24(&armor.data).to_be_bytes()[1..]));
+    encoded.push_str(&format!("\n-----END {label}-----\n"));
+
+    encoded
+}
+
+/// parses a whole armored block
+/// any lines that come before the begin line, e.g., mail headers, are skipped
+/// and both "\n" and "\r\n" line endings are accepted
+///
+/// the text of a clear signed message is skipped as well, it decodes into its signature block
+///
+/// the checksum line can be missing, it is only verified when it is there
+///
+/// # Error
+/// returns an Err if the begin or end lines are missing or malformed, if a header is malformed,
+/// if the body is not valid base64 or if the checksum doesn't match the data
+#[cfg(feature = "decoding")]
+pub fn decode(value: &str) -> Result<Armor, ArmorError> {
+    let mut lines = value
+        .lines()
+        .map(str::trim_end)
+        .skip_while(|l| !l.starts_with("-----BEGIN ") || *l == CLEARSIGN_BEGIN);
+    let label = lines
+        .next()
+        .and_then(|l| l.strip_prefix("-----BEGIN ")?.strip_suffix("-----"))
+        .ok_or(ArmorError::MissingBegin)?;
+    let kind = ArmorKind::from_label(label).ok_or(ArmorError::UnknownKind(label.into()))?;
+
+    let mut headers = Vec::new();
+    for line in lines.by_ref().take_while(|l| !l.is_empty()) {
+        let (name, value) = line
+            .split_once(": ")
+            .ok_or(ArmorError::BadHeader(line.into()))?;
+        headers.push((name.into(), value.into()));
+    }
+
+    let mut body = String::new();
+    let mut checksum = None;
+    let end = format!("-----END {label}-----");
+    for line in lines {
+        if line == end {
+            let data = Decoder::decode_to_bytes(body, BASE64).map_err(ArmorError::DecodeError)?;
+            if let Some(expected) = checksum {
+                let found = crc24(&data);
+                if found != expected {
+                    return Err(ArmorError::BadChecksum { expected, found });
+                }
+            }
+
+            return Ok(Armor {
+                kind,
+                headers,
+                data,
+            });
+        }
+
+        match line.strip_prefix('=') {
+            // the body lines end with the padding, none of them starts with '='
+            Some(crc) if checksum.is_none() => {
+                let crc = Decoder::decode_to_bytes(crc, BASE64).map_err(ArmorError::DecodeError)?;
+                let [a, b, c] = crc[..] else {
+                    return Err(ArmorError::DecodeError(DecodeError::BadLenForBase(
+                        line.len() - 1,
+                    )));
+                };
+                checksum = Some(u32::from_be_bytes([0, a, b, c]));
+            }
+            Some(_) => return Err(ArmorError::MissingEnd),
+            None if checksum.is_some() => return Err(ArmorError::MissingEnd),
+            None => body.push_str(line),
+        }
+    }
+
+    Err(ArmorError::MissingEnd)
+}
@@ -1,46 +1,82 @@
-#![cfg(feature = "base45")]
+#![cfg(any(feature = "base36", feature = "base58", feature = "base62"))]
 use crate::makura_alloc::Vec;
-use crate::makura_core::ops;
 
-pub(crate) struct BaseTransformer {
-    base: u8,
-    value: u64,
+// transforms an arbitrary length big integer between 2 bases
+// the value is given as a sequence of digits in the `from` base, most significant digit first
+//
+// leading zero digits carry no value, so each one of them is kept as a leading zero digit
+// in the `to` base; this is what makes transforming back and forth lossless
+#[cfg(any(feature = "base36", feature = "base58", feature = "base62"))]
+pub(crate) struct BigBaseTransformer<'a> {
+    from: u32,
+    to: u32,
+    digits: &'a [u8],
     seq: Vec<u8>,
 }
 
-impl BaseTransformer {
-    pub(crate) fn new(base: u8, value: impl ops::Shl + ops::MulAssign + Into<u64>) -> Self {
+#[cfg(any(feature = "base36", feature = "base58", feature = "base62"))]
+impl<'a> BigBaseTransformer<'a> {
+    pub(crate) fn new(from: u32, to: u32, digits: &'a [u8]) -> Self {
         Self {
-            base,
-            value: value.into(),
+            from,
+            to,
+            digits,
             seq: Vec::new(),
         }
     }
 
-    // does only 1 division of the whole transform sequence
-    // returns owned partially transformed self
-    pub(crate) fn transform(mut self) -> Self {
-        if self.value % self.base as u64 != 0 {
-            self.value = self.value / self.base as u64;
-            self.seq.push(self.value as u8);
-        }
-
-        self
-    }
-
     // does the whole transform sequence all at once
-    // modifies self in place
-    // reverses the seq vector as a bonus
+    // every pass divides the whole big integer by the `to` base
+    // the residual of the division is the next least significant digit
+    //
+    // the buffers are all allocated up front and never reallocated,
+    // so that they can be wiped once they are done with
     pub(crate) fn transform_all(&mut self) {
-        while self.value % self.base as u64 != 0 {
-            let res = self.value % self.base as u64;
-            self.value = self.value / self.base as u64;
+        let zeros = self.digits.iter().take_while(|d| **d == 0).count();
+        let mut value = self.digits[zeros..].to_vec();
+        let mut quotient = Vec::with_capacity(value.len());
+        self.seq.reserve(self.max_len(zeros));
+
+        while !value.is_empty() {
+            let mut res = 0u32;
+            quotient.clear();
+            for d in value.iter() {
+                let acc = res * self.from + *d as u32;
+                res = acc % self.to;
+                if !quotient.is_empty() || acc / self.to != 0 {
+                    quotient.push((acc / self.to) as u8);
+                }
+            }
             self.seq.push(res as u8);
+            core::mem::swap(&mut value, &mut quotient);
         }
+        crate::wipe(&mut value);
+        crate::wipe(&mut quotient);
+
+        self.seq.extend((0..zeros).map(|_| 0));
+        self.seq.reverse();
     }
 
-    // returns the reversed sequence of residual values
+    // an upper bound of the count of digits that the transform makes,
+    // every leading zero digit is kept as a single zero digit,
+    // every other `from` digit holds at most as many bits as the bit length of `from - 1`,
+    // and every `to` digit holds at least as many bits as the floor of log2(to)
+    fn max_len(&self, zeros: usize) -> usize {
+        let from_bits = (u32::BITS - (self.from - 1).leading_zeros()) as usize;
+        let to_bits = (u32::BITS - 1 - self.to.leading_zeros()) as usize;
+
+        zeros + (self.digits.len() - zeros) * from_bits / to_bits + 1
+    }
+
+    // returns the transformed sequence, most significant digit first
     pub(crate) fn sequence(&self) -> &[u8] {
         self.seq.as_slice()
     }
 }
+
+#[cfg(any(feature = "base36", feature = "base58", feature = "base62"))]
+impl Drop for BigBaseTransformer<'_> {
+    fn drop(&mut self) {
+        crate::wipe(&mut self.seq);
+    }
+}
@@ -0,0 +1,247 @@
+#![cfg(feature = "bech32")]
+//! bech32 and bech32m strings, as specified by [BIP-173](https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki)
+//! and [BIP-350](https://github.com/bitcoin/bips/blob/master/bip-0350.mediawiki)
+//!
+//! a bech32 string is made of a human readable part, the '1' separator,
+//! the data regrouped into 5 bits values and a 6 chars BCH checksum,
+//! e.g., "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw"
+use crate::makura_alloc::{String, Vec};
+
+/// the 5 bits values table
+const CHARSET: &[u8; 32] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// a bech32 string can't be longer than this
+const MAX_LEN: usize = 90;
+
+/// the human readable part's length range
+const HRP_LEN: core::ops::RangeInclusive<usize> = 1..=83;
+
+const CHECKSUM_LEN: usize = 6;
+
+/// the checksum flavor of a bech32 string
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Variant {
+    /// the original BIP-173 checksum
+    Bech32,
+    /// the BIP-350 checksum
+    Bech32m,
+}
+
+impl Variant {
+    // the value that the checksum polymod has to xor to
+    fn constant(&self) -> u32 {
+        match self {
+            Self::Bech32 => 1,
+            Self::Bech32m => 0x2bc830a3,
+        }
+    }
+}
+
+/// errors that can occur while encoding or decoding a bech32 string
+#[derive(Debug, PartialEq)]
+pub enum Bech32Error {
+    /// the bech32 string is longer than 90 chars
+    /// .0 corresponds to the bad length value
+    TooLong(usize),
+    /// the bech32 string mixes lowercase and uppercase chars
+    MixedCase,
+    /// no '1' separator was found in the bech32 string
+    MissingSeparator,
+    /// the human readable part is either empty or longer than 83 chars
+    /// .0 corresponds to the bad length value
+    BadHrpLen(usize),
+    /// a human readable part char is out of the printable ascii range 33 -> 126
+    /// idx is the position of the char in the bech32 string
+    InvalidHrpChar { ch: char, idx: usize },
+    /// a data part char is not part of the bech32 table
+    /// idx is the position of the char in the bech32 string
+    InvalidChar { ch: char, idx: usize },
+    /// the data part is shorter than the 6 chars checksum
+    /// .0 corresponds to the bad length value
+    MissingChecksum(usize),
+    /// the checksum doesn't match the human readable part and data,
+    /// for neither bech32 nor bech32m
+    InvalidChecksum,
+    /// the 5 bits data values don't regroup into whole bytes,
+    /// the left over bits are either 5 or more, or are not all zeros
+    InvalidPadding,
+}
+
+// the BCH code generator
+fn polymod(values: impl Iterator<Item = u8>) -> u32 {
+    const GEN: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+    values.fold(1u32, |chk, v| {
+        let top = chk >> 25;
+        let chk = ((chk & 0x1ffffff) << 5) ^ v as u32;
+
+        GEN.iter()
+            .enumerate()
+            .filter(|(i, _)| (top >> i) & 1 == 1)
+            .fold(chk, |chk, (_, g)| chk ^ g)
+    })
+}
+
+// the hrp chars' high bits, a zero, then the hrp chars' low bits
+fn hrp_expand(hrp: &[u8]) -> impl Iterator<Item = u8> + '_ {
+    hrp.iter()
+        .map(|c| c >> 5)
+        .chain([0])
+        .chain(hrp.iter().map(|c| c & 31))
+}
+
+fn checksum(hrp: &[u8], values: &[u8], variant: Variant) -> [u8; CHECKSUM_LEN] {
+    let poly = polymod(
+        hrp_expand(hrp)
+            .chain(values.iter().copied())
+            .chain([0; CHECKSUM_LEN]),
+    ) ^ variant.constant();
+
+    core::array::from_fn(|i| ((poly >> (5 * (5 - i))) & 31) as u8)
+}
+
+fn check_hrp(hrp: &str) -> Result<(), Bech32Error> {
+    if !HRP_LEN.contains(&hrp.len()) {
+        return Err(Bech32Error::BadHrpLen(hrp.len()));
+    }
+    if let Some((idx, ch)) = hrp.char_indices().find(|(_, c)| !('!'..='~').contains(c)) {
+        return Err(Bech32Error::InvalidHrpChar { ch, idx });
+    }
+    if hrp.contains(|c: char| c.is_ascii_lowercase())
+        && hrp.contains(|c: char| c.is_ascii_uppercase())
+    {
+        return Err(Bech32Error::MixedCase);
+    }
+
+    Ok(())
+}
+
+/// encodes already regrouped 5 bits values into a bech32 string
+/// use this one for data that isn't made of whole bytes,
+/// e.g., a segwit address's witness version followed by its regrouped witness program
+///
+/// the output is always lowercase
+///
+/// # Error
+/// returns an Err if the human readable part is invalid
+/// or if the encoded string would be longer than 90 chars
+///
+/// # Panic
+/// panics if any of the values doesn't fit in 5 bits
+#[cfg(feature = "encoding")]
+pub fn encode_5bits(hrp: &str, values: &[u8], variant: Variant) -> Result<String, Bech32Error> {
+    check_hrp(hrp)?;
+    assert!(
+        values.iter().all(|v| *v < 32),
+        "bech32 data values must fit in 5 bits"
+    );
+    let len = hrp.len() + 1 + values.len() + CHECKSUM_LEN;
+    if len > MAX_LEN {
+        return Err(Bech32Error::TooLong(len));
+    }
+
+    let hrp = hrp.to_ascii_lowercase();
+    let checksum = checksum(hrp.as_bytes(), values, variant);
+
+    let mut encoded = String::with_capacity(len);
+    encoded.push_str(&hrp);
+    encoded.push('1');
+    encoded.extend(
+        values
+            .iter()
+            .chain(checksum.iter())
+            .map(|v| CHARSET[*v as usize] as char),
+    );
+
+    Ok(encoded)
+}
+
+/// encodes the data bytes into a bech32 string with the given human readable part
+/// the data is regrouped from 8 bits bytes into 5 bits values, the last value is zero filled
+///
+/// # Error
+/// returns an Err if the human readable part is invalid
+/// or if the encoded string would be longer than 90 chars
+#[cfg(feature = "encoding")]
+pub fn encode(hrp: &str, data: impl AsRef<[u8]>, variant: Variant) -> Result<String, Bech32Error> {
+    let data = data.as_ref();
+    let values = crate::encoders::into_5bits_values(data, data.len() * 8);
+
+    encode_5bits(hrp, &values, variant)
+}
+
+/// decodes a bech32 string into its human readable part and its 5 bits data values,
+/// the checksum is verified and stripped
+///
+/// the returned human readable part is always lowercase
+///
+/// # Error
+/// returns an Err when the string is not a valid bech32 or bech32m string,
+/// see the `Bech32Error` variants
+#[cfg(feature = "decoding")]
+pub fn decode_5bits(value: &str) -> Result<(String, Vec<u8>, Variant), Bech32Error> {
+    if value.len() > MAX_LEN {
+        return Err(Bech32Error::TooLong(value.len()));
+    }
+    if value.contains(|c: char| c.is_ascii_lowercase())
+        && value.contains(|c: char| c.is_ascii_uppercase())
+    {
+        return Err(Bech32Error::MixedCase);
+    }
+    let Some(sep) = value.rfind('1') else {
+        return Err(Bech32Error::MissingSeparator);
+    };
+
+    let (hrp, data) = (&value[..sep], &value[sep + 1..]);
+    check_hrp(hrp)?;
+    if data.len() < CHECKSUM_LEN {
+        return Err(Bech32Error::MissingChecksum(data.len()));
+    }
+
+    let hrp = hrp.to_ascii_lowercase();
+    let mut values = data
+        .char_indices()
+        .map(|(idx, ch)| {
+            CHARSET
+                .iter()
+                .position(|c| *c as char == ch.to_ascii_lowercase())
+                .map(|v| v as u8)
+                .ok_or(Bech32Error::InvalidChar {
+                    ch,
+                    idx: sep + 1 + idx,
+                })
+        })
+        .collect::<Result<Vec<u8>, Bech32Error>>()?;
+
+    let poly = polymod(hrp_expand(hrp.as_bytes()).chain(values.iter().copied()));
+    let variant = [Variant::Bech32, Variant::Bech32m]
+        .into_iter()
+        .find(|v| v.constant() == poly)
+        .ok_or(Bech32Error::InvalidChecksum)?;
+    values.truncate(values.len() - CHECKSUM_LEN);
+
+    Ok((hrp, values, variant))
+}
+
+/// decodes a bech32 string into its human readable part and its data bytes
+/// the 5 bits values are regrouped back into 8 bits bytes
+///
+/// # Error
+/// returns an Err when the string is not a valid bech32 or bech32m string,
+/// or when its data values don't regroup into whole bytes
+#[cfg(feature = "decoding")]
+pub fn decode(value: &str) -> Result<(String, Vec<u8>, Variant), Bech32Error> {
+    let (hrp, values, variant) = decode_5bits(value)?;
+
+    let bits = values.len() * 5;
+    let pad = bits % 8;
+    if pad >= 5 || values.last().is_some_and(|v| v & ((1 << pad) - 1) != 0) {
+        return Err(Bech32Error::InvalidPadding);
+    }
+
+    Ok((
+        hrp,
+        crate::decoders::base32_z_decode_bits(&values, bits - pad),
+        variant,
+    ))
+}
@@ -0,0 +1,318 @@
+#![cfg(any(
+    feature = "base64",
+    feature = "base64_url",
+    feature = "base32",
+    feature = "base16"
+))]
+//! constant time base 64, base 64 url, base 32 and base 16 codecs for secret material,
+//! e.g., private keys, jwk `d` values or totp secrets
+//!
+//! the regular codecs map symbols through `char_from_idx`/`idx_from_char`,
+//! whose match arms take a time that depends on the symbol,
+//! and they return as soon as they meet a bad char.
+//! here, every symbol is mapped with arithmetic on range masks instead,
+//! there are no branches or table lookups that depend on the secret,
+//! and a bad char is only reported once the whole value was read
+//!
+//! the length of the value, and of its padding, is not treated as a secret
+//!
+//! ```
+//! use makura::ct;
+//!
+//! let encoded = ct::base64_encode(b"secret");
+//! assert_eq!(encoded, "c2VjcmV0");
+//! assert_eq!(ct::base64_decode(&encoded).unwrap(), b"secret");
+//! assert!(ct::eq(&encoded, "c2VjcmV0"));
+//! ```
+use crate::makura_alloc::{String, Vec};
+
+#[cfg(feature = "decoding")]
+use crate::DecodeError;
+use crate::PAD;
+
+// -1 if lo <= c <= hi, 0 otherwise
+// both differences are negative only when c is in the range, the sign bit is spread to all bits
+#[cfg(feature = "decoding")]
+fn in_range(c: i32, lo: u8, hi: u8) -> i32 {
+    ((lo as i32 - 1 - c) & (c - hi as i32 - 1)) >> 31
+}
+
+// -1 if idx > last, 0 otherwise
+#[cfg(feature = "encoding")]
+fn above(idx: i32, last: i32) -> i32 {
+    (last - idx) >> 31
+}
+
+// maps a base 64 index to its char
+// the index starts in the 'A'..='Z' range, every range that it goes past shifts it by
+// the distance between the end of that range and the start of the next one
+#[cfg(all(any(feature = "base64", feature = "base64_url"), feature = "encoding"))]
+fn base64_char(idx: u8, c62: u8, c63: u8) -> char {
+    let idx = idx as i32;
+    let mut c = idx + b'A' as i32;
+    c += above(idx, 25) & (b'a' as i32 - b'Z' as i32 - 1);
+    c -= above(idx, 51) & (b'z' as i32 - b'0' as i32 + 1);
+    c += above(idx, 61) & (c62 as i32 - b'9' as i32 - 1);
+    c += above(idx, 62) & (c63 as i32 - c62 as i32 - 1);
+
+    c as u8 as char
+}
+
+// maps a base 64 char to its index, along with a mask that is -1 if the char is in the table
+#[cfg(all(any(feature = "base64", feature = "base64_url"), feature = "decoding"))]
+fn base64_value(c: u8, c62: u8, c63: u8) -> (u8, i32) {
+    let c = c as i32;
+    let upper = in_range(c, b'A', b'Z');
+    let lower = in_range(c, b'a', b'z');
+    let digit = in_range(c, b'0', b'9');
+    let is_62 = in_range(c, c62, c62);
+    let is_63 = in_range(c, c63, c63);
+    let idx = (upper & (c - b'A' as i32))
+        | (lower & (c - b'a' as i32 + 26))
+        | (digit & (c - b'0' as i32 + 52))
+        | (is_62 & 62)
+        | (is_63 & 63);
+
+    (idx as u8, upper | lower | digit | is_62 | is_63)
+}
+
+#[cfg(all(any(feature = "base64", feature = "base64_url"), feature = "encoding"))]
+fn encode_6bits(value: &[u8], c62: u8, c63: u8) -> String {
+    let mut encoded = String::with_capacity(value.len().div_ceil(3) * 4);
+    value.chunks(3).for_each(|chunk| {
+        let values = crate::encoders::into_6bits_values(chunk);
+        let len = chunk.len() + 1;
+        values[..len]
+            .iter()
+            .for_each(|b| encoded.push(base64_char(*b, c62, c63)));
+        (len..4).for_each(|_| encoded.push(PAD));
+    });
+
+    encoded
+}
+
+#[cfg(all(any(feature = "base64", feature = "base64_url"), feature = "decoding"))]
+fn decode_6bits(value: &str, c62: u8, c63: u8) -> Result<Vec<u8>, DecodeError> {
+    let value = value.trim_end_matches(PAD).as_bytes();
+    if value.len() % 4 == 1 {
+        return Err(DecodeError::BadLenForBase(value.len()));
+    }
+
+    let mut valid = -1;
+    let mut bytes = Vec::with_capacity(value.len() / 4 * 3 + 2);
+    value.chunks(4).for_each(|chunk| {
+        let mut values = [0u8; 4];
+        chunk.iter().zip(values.iter_mut()).for_each(|(c, v)| {
+            let (idx, is_valid) = base64_value(*c, c62, c63);
+            *v = idx;
+            valid &= is_valid;
+        });
+        let decoded = crate::decoders::into_8bits_values(&values[..chunk.len()]);
+        bytes.extend_from_slice(&decoded[..chunk.len() * 6 / 8]);
+    });
+
+    match valid {
+        -1 => Ok(bytes),
+        _ => Err(DecodeError::BadEncodedString),
+    }
+}
+
+/// encodes the value into padded base 64 in constant time
+#[cfg(all(feature = "base64", feature = "encoding"))]
+pub fn base64_encode(value: impl AsRef<[u8]>) -> String {
+    encode_6bits(value.as_ref(), b'+', b'/')
+}
+
+/// decodes a padded or unpadded base 64 value in constant time
+///
+/// # Error
+/// returns an Err if the value has a bad length or holds chars that are not in the base 64 table,
+/// the error doesn't tell which char was bad
+#[cfg(all(feature = "base64", feature = "decoding"))]
+pub fn base64_decode(value: impl AsRef<str>) -> Result<Vec<u8>, DecodeError> {
+    decode_6bits(value.as_ref(), b'+', b'/')
+}
+
+/// encodes the value into padded base 64 url in constant time
+#[cfg(all(feature = "base64_url", feature = "encoding"))]
+pub fn base64_url_encode(value: impl AsRef<[u8]>) -> String {
+    encode_6bits(value.as_ref(), b'-', b'_')
+}
+
+/// decodes a padded or unpadded base 64 url value in constant time
+///
+/// # Error
+/// returns an Err if the value has a bad length or holds chars that are not in the
+/// base 64 url table, the error doesn't tell which char was bad
+#[cfg(all(feature = "base64_url", feature = "decoding"))]
+pub fn base64_url_decode(value: impl AsRef<str>) -> Result<Vec<u8>, DecodeError> {
+    decode_6bits(value.as_ref(), b'-', b'_')
+}
+
+// maps a base 32 index to its char, 'A'..='Z' then '2'..='7'
+#[cfg(all(feature = "base32", feature = "encoding"))]
+fn base32_char(idx: u8) -> char {
+    let idx = idx as i32;
+    let c = idx + b'A' as i32 - (above(idx, 25) & (b'Z' as i32 - b'2' as i32 + 1));
+
+    c as u8 as char
+}
+
+// maps a base 32 char to its index, lowercase chars are accepted as well,
+// along with a mask that is -1 if the char is in the table
+#[cfg(all(feature = "base32", feature = "decoding"))]
+fn base32_value(c: u8) -> (u8, i32) {
+    let c = c as i32;
+    let upper = in_range(c, b'A', b'Z');
+    let lower = in_range(c, b'a', b'z');
+    let digit = in_range(c, b'2', b'7');
+    let idx = (upper & (c - b'A' as i32))
+        | (lower & (c - b'a' as i32))
+        | (digit & (c - b'2' as i32 + 26));
+
+    (idx as u8, upper | lower | digit)
+}
+
+/// encodes the value into padded base 32 in constant time
+#[cfg(all(feature = "base32", feature = "encoding"))]
+pub fn base32_encode(value: impl AsRef<[u8]>) -> String {
+    let value = value.as_ref();
+    let mut encoded = String::with_capacity(value.len().div_ceil(5) * 8);
+    value.chunks(5).for_each(|chunk| {
+        let values = crate::encoders::into_5bits_chunk(chunk);
+        let len = (chunk.len() * 8).div_ceil(5);
+        values[..len]
+            .iter()
+            .for_each(|b| encoded.push(base32_char(*b)));
+        (len..8).for_each(|_| encoded.push(PAD));
+    });
+
+    encoded
+}
+
+/// decodes a padded or unpadded base 32 value in constant time,
+/// lowercase chars are accepted as well
+///
+/// # Error
+/// returns an Err if the value has a bad length or holds chars that are not in the base 32 table,
+/// the error doesn't tell which char was bad
+#[cfg(all(feature = "base32", feature = "decoding"))]
+pub fn base32_decode(value: impl AsRef<str>) -> Result<Vec<u8>, DecodeError> {
+    let value = value.as_ref().trim_end_matches(PAD).as_bytes();
+    // no count of whole bytes is encoded into 1, 3 or 6 chars
+    if matches!(value.len() % 8, 1 | 3 | 6) {
+        return Err(DecodeError::BadLenForBase(value.len()));
+    }
+
+    let mut valid = -1;
+    let mut bytes = Vec::with_capacity(value.len() / 8 * 5 + 4);
+    value.chunks(8).for_each(|chunk| {
+        let mut values = [0u8; 8];
+        chunk.iter().zip(values.iter_mut()).for_each(|(c, v)| {
+            let (idx, is_valid) = base32_value(*c);
+            *v = idx;
+            valid &= is_valid;
+        });
+        let decoded = crate::decoders::into_8bits_chunk(&values[..chunk.len()]);
+        bytes.extend_from_slice(&decoded[..chunk.len() * 5 / 8]);
+    });
+
+    match valid {
+        -1 => Ok(bytes),
+        _ => Err(DecodeError::BadEncodedString),
+    }
+}
+
+// maps a nibble to its hex digit, the case is not a secret
+#[cfg(all(feature = "base16", feature = "encoding"))]
+fn hex_char(nibble: u8, lowercase: bool) -> char {
+    let nibble = nibble as i32;
+    let alpha = if lowercase { b'a' } else { b'A' };
+    let c = nibble + b'0' as i32 + (above(nibble, 9) & (alpha as i32 - b'9' as i32 - 1));
+
+    c as u8 as char
+}
+
+// maps a hex digit of either case to its nibble,
+// along with a mask that is -1 if the char is a hex digit
+#[cfg(all(feature = "base16", feature = "decoding"))]
+fn hex_value(c: u8) -> (u8, i32) {
+    let c = c as i32;
+    let digit = in_range(c, b'0', b'9');
+    let upper = in_range(c, b'A', b'F');
+    let lower = in_range(c, b'a', b'f');
+    let nibble = (digit & (c - b'0' as i32))
+        | (upper & (c - b'A' as i32 + 10))
+        | (lower & (c - b'a' as i32 + 10));
+
+    (nibble as u8, digit | upper | lower)
+}
+
+#[cfg(all(feature = "base16", feature = "encoding"))]
+fn encode_hex(value: &[u8], lowercase: bool) -> String {
+    let mut encoded = String::with_capacity(value.len() * 2);
+    value.iter().for_each(|b| {
+        encoded.push(hex_char(b >> 4, lowercase));
+        encoded.push(hex_char(b & 15, lowercase));
+    });
+
+    encoded
+}
+
+/// encodes the value into uppercase base 16 in constant time
+#[cfg(all(feature = "base16", feature = "encoding"))]
+pub fn base16_encode(value: impl AsRef<[u8]>) -> String {
+    encode_hex(value.as_ref(), false)
+}
+
+/// encodes the value into lowercase base 16 in constant time
+#[cfg(all(feature = "base16", feature = "encoding"))]
+pub fn base16_lower_encode(value: impl AsRef<[u8]>) -> String {
+    encode_hex(value.as_ref(), true)
+}
+
+/// decodes a base 16 value of either case in constant time
+///
+/// # Error
+/// returns an Err if the value has an odd length or holds chars that are not hex digits,
+/// the error doesn't tell which char was bad
+#[cfg(all(feature = "base16", feature = "decoding"))]
+pub fn base16_decode(value: impl AsRef<str>) -> Result<Vec<u8>, DecodeError> {
+    let value = value.as_ref().as_bytes();
+    if value.len() % 2 == 1 {
+        return Err(DecodeError::BadLenForBase(value.len()));
+    }
+
+    let mut valid = -1;
+    let bytes = value
+        .chunks(2)
+        .map(|pair| {
+            let (hi, hi_valid) = hex_value(pair[0]);
+            let (lo, lo_valid) = hex_value(pair[1]);
+            valid &= hi_valid & lo_valid;
+
+            hi << 4 | lo
+        })
+        .collect();
+
+    match valid {
+        -1 => Ok(bytes),
+        _ => Err(DecodeError::BadEncodedString),
+    }
+}
+
+/// compares two encoded tokens, e.g., api keys or csrf tokens, in constant time
+///
+/// every byte of the tokens is compared, whichever byte is the first to differ;
+/// only the lengths of the tokens are compared up front, since they are not secret
+pub fn eq(a: impl AsRef<[u8]>, b: impl AsRef<[u8]>) -> bool {
+    let (a, b) = (a.as_ref(), b.as_ref());
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let diff = a.iter().zip(b).fold(0u8, |diff, (a, b)| diff | (a ^ b));
+
+    // keeps the optimizer from turning the fold into an early exit
+    core::hint::black_box(diff) == 0
+}
@@ -0,0 +1,156 @@
+#![cfg(feature = "data_url")]
+//! [RFC 2397](https://datatracker.ietf.org/doc/html/rfc2397) data urls
+//!
+//! ```text
+//! data:[<mediatype>][;<name>=<value>]*[;base64],<data>
+//! ```
+//!
+//! e.g., "data:image/png;base64,iVBORw0KGgo=" or "data:,Hello%2C%20World%21"
+//!
+//! urls are always built with the base64 form,
+//! both the base64 and the percent encoded forms are parsed
+use crate::makura_alloc::{String, Vec};
+
+use crate::percent_encoding::{self, PercentError, PercentSet};
+use crate::{BASE64, DecodeError, Decoder, Encoder};
+
+/// the media type of a data url that has none
+const DEFAULT_MEDIA_TYPE: &str = "text/plain";
+
+/// the charset of a data url that has neither a media type nor parameters
+const DEFAULT_CHARSET: &str = "US-ASCII";
+
+/// a data url's media type, parameters and data
+#[derive(Debug, PartialEq, Clone)]
+pub struct DataUrl {
+    /// the mime type, e.g., "image/png"
+    pub media_type: String,
+    /// the media type parameters in their url order, e.g., ("charset", "utf-8")
+    pub params: Vec<(String, String)>,
+    /// the decoded data
+    pub data: Vec<u8>,
+}
+
+impl DataUrl {
+    /// creates a new data url from its mime type and data, without any parameters
+    pub fn new(media_type: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            media_type: media_type.into(),
+            params: Vec::new(),
+            data: data.into(),
+        }
+    }
+
+    /// adds a media type parameter, e.g., `.param("charset", "utf-8")`
+    pub fn param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.params.push((name.into(), value.into()));
+
+        self
+    }
+
+    /// returns the value of the charset parameter, if there is one
+    pub fn charset(&self) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("charset"))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// errors that can occur while parsing a data url
+#[derive(Debug)]
+pub enum DataUrlError {
+    /// the url doesn't start with the "data:" scheme
+    MissingScheme,
+    /// no ',' separates the media type from the data
+    MissingComma,
+    /// a '%' is not followed by 2 hex digits
+    /// .0 corresponds to the position of the '%' in the data
+    BadPercentEncoding(usize),
+    /// the data of a base64 data url failed to decode
+    DecodeError(DecodeError),
+}
+
+/// builds the base64 form of the data url
+///
+/// the parameters are written in their order and as they are, no quoting is added
+#[cfg(feature = "encoding")]
+pub fn encode(url: &DataUrl) -> String {
+    let data = Encoder::base64().encode_bytes(&url.data);
+
+    let mut encoded = String::with_capacity(url.media_type.len() + data.len() + 13);
+    encoded.push_str("data:");
+    encoded.push_str(&url.media_type);
+    url.params.iter().for_each(|(name, value)| {
+        encoded.push(';');
+        encoded.push_str(name);
+        encoded.push('=');
+        encoded.push_str(value);
+    });
+    encoded.push_str(";base64,");
+    encoded.push_str(&data);
+
+    encoded
+}
+
+/// parses a data url
+///
+/// the media type and parameter names are lowercased,
+/// a url without a media type gets the "text/plain;charset=US-ASCII" default of rfc 2397
+///
+/// the base64 data can contain percent escapes and whitespace, and can be missing its padding
+///
+/// # Error
+/// returns an Err if the url is not a data url,
+/// or if its data is neither valid percent encoded data nor valid base64
+#[cfg(feature = "decoding")]
+pub fn decode(value: &str) -> Result<DataUrl, DataUrlError> {
+    let value = value.trim();
+    let rest = value
+        .get(..5)
+        .filter(|scheme| scheme.eq_ignore_ascii_case("data:"))
+        .map(|_| &value[5..])
+        .ok_or(DataUrlError::MissingScheme)?;
+    let (header, data) = rest.split_once(',').ok_or(DataUrlError::MissingComma)?;
+
+    let mut parts = header.split(';');
+    let media_type = parts.next().unwrap_or_default().trim().to_ascii_lowercase();
+    let mut params = Vec::new();
+    let mut base64 = false;
+    for part in parts {
+        match part.split_once('=') {
+            Some((name, value)) => params.push((name.trim().to_ascii_lowercase(), value.into())),
+            None if part.trim().eq_ignore_ascii_case("base64") => base64 = true,
+            // a parameter without a value carries no information
+            None => {}
+        }
+    }
+
+    let mut url = DataUrl {
+        media_type,
+        params,
+        data: percent_encoding::decode(data, PercentSet::UNRESERVED)
+            .map_err(|PercentError::BadEscape(idx)| DataUrlError::BadPercentEncoding(idx))?,
+    };
+    if url.media_type.is_empty() {
+        url.media_type = DEFAULT_MEDIA_TYPE.into();
+        if url.params.is_empty() {
+            url.params.push(("charset".into(), DEFAULT_CHARSET.into()));
+        }
+    }
+
+    if base64 {
+        let mut data = url
+            .data
+            .iter()
+            .filter(|b| !b.is_ascii_whitespace())
+            .map(|b| *b as char)
+            .collect::<String>();
+        let pad = data.len().next_multiple_of(4) - data.len();
+        data.extend((0..pad).map(|_| crate::PAD));
+
+        url.data = Decoder::decode_to_bytes(data, BASE64).map_err(DataUrlError::DecodeError)?;
+    }
+
+    Ok(url)
+}
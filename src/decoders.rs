@@ -2,21 +2,44 @@
 use crate::makura_alloc::{String, Vec};
 
 use super::{Base, idx_from_char};
+use crate::iter::DecodeIter;
 
 mod base16;
 mod base32;
 mod base45;
+mod base62;
 mod base64;
+mod base85;
+mod base91;
 
 use base16::base16_decode;
 use base32::base32_decode;
 use base32::base32_hex_decode;
-use base45::base45_decode;
+use base32::base32_nsec3_decode;
+use base32::base32_z_decode;
+pub(crate) use base32::base32_z_decode_bits;
+#[cfg(feature = "base32")]
+pub(crate) use base32::into_8bits_chunk;
+use base45::{base45_decode, into_8bits_pair};
+use base62::{base36_decode, base58_decode, base62_decode};
+use base64::base64_crypt_decode;
 use base64::base64_decode;
+use base64::base64_profile_decode;
 use base64::base64_url_decode;
+#[cfg(any(feature = "uuencode", feature = "base64", feature = "base64_url"))]
+pub(crate) use base64::into_8bits_values;
+#[cfg(feature = "base85_ascii")]
+use base85::ascii85_unframe;
+use base85::{base85_decode, into_8bits_quad};
+use base91::base91_decode;
+use exclusion::BaseExclusion;
 
 use crate::makura_alloc::FromUtf8Error;
-use crate::{BASE16, BASE32, BASE32HEX, BASE45, BASE64, BASE64URL};
+use crate::{
+    BASE16, BASE32, BASE32HEX, BASE32NSEC3, BASE32Z, BASE36, BASE45, BASE58, BASE62, BASE64,
+    BASE64BCRYPT, BASE64CRYPT, BASE64IMAP, BASE64URL, BASE64XMLNAME, BASE64XMLNMTOKEN, BASE64Y64,
+    BASE85, BASE85ASCII, BASE85Z, BASE91,
+};
 
 /// errors that can occur during the decoding process of some base encoded input value
 #[derive(Debug)]
@@ -45,8 +68,8 @@ pub enum DecodeError {
     /// e.g., a base64 encoded string bytes should all satisfy 0 < byte <= 63
     /// .0 corresponds to the value that is not found in the encoding alphabet table
     TableIndexOverflow(u8),
-    /// when decoding an encoded string that is supposed to be of base 16 or 45
-    /// both of which can not contain padding '=' chars
+    /// when decoding an encoded string that is supposed to be of a base that has no padding,
+    /// e.g., base 16 or 45, which can not contain padding '=' chars
     /// yet a padding char was found at the end of the encoded string
     BaseEncodingHasNoPaddingChars(Base),
     /// results from trying togenerate a string from a Vec<u8> decoded bytes of an
@@ -59,8 +82,47 @@ pub enum DecodeError {
     /// signifies that the correct base was deduced but it has been excluded from the deduction
     /// the deduction process exits with this error value since further deduction is useless
     EncodingBaseIsExcluded(Base),
+    /// a group of 5 base 85 chars decodes into a value that doesn't fit into the 4 bytes
+    /// that such a group can encode
+    /// .0 corresponds to the index of the overflowing group
+    Base85GroupOverflow(usize),
+    /// a group of 3 base 45 chars decodes into a value that doesn't fit into the 2 bytes
+    /// that such a group can encode, or a final group of 2 chars into a value over 255
+    /// .0 corresponds to the index of the overflowing group
+    Base45GroupOverflow(usize),
+    /// the operation can't be done with the given base,
+    /// e.g., in place decoding of a base that is not made of independent blocks
+    UnsupportedForBase(Base),
 }
 
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::BadEncodedString => write!(f, "badly encoded value"),
+            Self::BadLenForBase(len) => write!(f, "bad encoded value length {len}"),
+            Self::UnknownBaseEncodingIfAny => write!(f, "unknown base encoding"),
+            Self::UnrecognizedCharForBase { ch, base } => {
+                write!(f, "char {ch:?} is not part of the {base:?} table")
+            }
+            Self::TableIndexOverflow(idx) => write!(f, "table index {idx} overflows the table"),
+            Self::BaseEncodingHasNoPaddingChars(base) => {
+                write!(f, "{base:?} has no padding chars")
+            }
+            Self::FromUtf8Error(err) => write!(f, "{err}"),
+            Self::EncodingBaseIsExcluded(base) => write!(f, "{base:?} is excluded"),
+            Self::Base85GroupOverflow(group) => write!(f, "base 85 group {group} overflows"),
+            Self::Base45GroupOverflow(group) => write!(f, "base 45 group {group} overflows"),
+            Self::UnsupportedForBase(base) => write!(f, "unsupported for {base:?}"),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+/// the rough number of encoded chars that each thread of decode_to_bytes_par takes at a time
+#[cfg(feature = "rayon")]
+const PAR_CHUNK_LEN: usize = 1 << 16;
+
 // this only exists to match Encoder struct
 // otherwise a free function works fine
 pub struct Decoder;
@@ -71,30 +133,98 @@ impl Decoder {
         if res.is_ok() {
             res.map_err(|_| DecodeError::BadEncodedString)
         } else {
-            res.map_err(|e| DecodeError::FromUtf8Error(e))
+            res.map_err(DecodeError::FromUtf8Error)
         }
     }
 
     // turns back chars from the encoding table to their table index values
     pub(self) fn into_table_idx(value: &str, base: &Base) -> Result<Vec<u8>, DecodeError> {
+        #[cfg(feature = "base85_ascii")]
+        if base == &BASE85ASCII {
+            let unframed = ascii85_unframe(value)?;
+            let indices = Self::chars_into_idx(&unframed, base);
+            crate::wipe(&mut unframed.into_bytes());
+
+            return indices;
+        }
+
+        Self::chars_into_idx(value, base)
+    }
+
+    // the indices buffer is large enough for all the chars up front, so it is never reallocated,
+    // which would leave a copy of the indices behind that can't be wiped
+    fn chars_into_idx(value: &str, base: &Base) -> Result<Vec<u8>, DecodeError> {
         // no need for chars count, len is sufficient since all chars are ascii (1 byte)
         // WARN they are not all ascii, baseless assumption
         // but i cant recall what the line above is talking about
-        let val = value.chars().map(|c| match c {
-            '=' => {
-                if base == &BASE16 || base == &BASE45 {
+        // the padding chars carry no bits, the decoders only get the chars that come before them
+        let value = match base.pad_char() {
+            Some(pad) => value.trim_end_matches(pad),
+            None => value,
+        };
+
+        let mut indices = Vec::with_capacity(value.len());
+        for c in value.chars() {
+            let idx = match c {
+                '=' if !base.has_eq_char() => {
                     Err(DecodeError::BaseEncodingHasNoPaddingChars(*base))
-                } else {
-                    Ok(0)
+                }
+                val => idx_from_char(val, base),
+            };
+            match idx {
+                Ok(idx) => indices.push(idx),
+                Err(_) => {
+                    crate::wipe(&mut indices);
+                    return Err(DecodeError::BadEncodedString);
                 }
             }
-            val => idx_from_char(val, base),
-        });
-        if val.clone().any(|i| i.is_err()) {
-            return Err(DecodeError::BadEncodedString);
         }
 
-        Ok(val.map(|i| i.unwrap()).collect::<Vec<u8>>())
+        Ok(indices)
+    }
+
+    // decodes a value at the end of the output, the leading whole blocks take the simd path
+    // when possible
+    //
+    // the output may hold some of the decoded bytes when an error is returned
+    fn decode_append(value: &str, base: &Base, decoded: &mut Vec<u8>) -> Result<(), DecodeError> {
+        #[cfg(feature = "simd")]
+        if let Some(read) = crate::simd::decode(value, base, decoded) {
+            return Self::decode_append(&value[read..], base, decoded).map_err(|err| match err {
+                DecodeError::BadLenForBase(len) => DecodeError::BadLenForBase(len + read),
+                err => err,
+            });
+        }
+
+        let mut indices = Self::into_table_idx(value, base)?;
+        let res = Self::decode_indices(&indices, base, decoded);
+        crate::wipe(&mut indices);
+
+        res
+    }
+
+    // decodes a chunk of a larger value that starts after `before` chars of whole blocks,
+    // the positions that an error holds are made relative to the whole value
+    pub(crate) fn decode_chunk(
+        chunk: &str,
+        base: &Base,
+        before: usize,
+        decoded: &mut Vec<u8>,
+    ) -> Result<(), DecodeError> {
+        if chunk.is_empty() {
+            return Ok(());
+        }
+
+        Self::decode_append(chunk, base, decoded).map_err(|err| match (err, base.block_len()) {
+            (DecodeError::Base85GroupOverflow(group), Some((_, block))) => {
+                DecodeError::Base85GroupOverflow(group + before / block)
+            }
+            (DecodeError::Base45GroupOverflow(group), Some((_, block))) => {
+                DecodeError::Base45GroupOverflow(group + before / block)
+            }
+            (DecodeError::BadLenForBase(len), _) => DecodeError::BadLenForBase(len + before),
+            (err, _) => err,
+        })
     }
 
     /// decodes a given string
@@ -116,20 +246,238 @@ impl Decoder {
         if value.is_empty() {
             return Ok("".into());
         }
-        let indices = Self::into_table_idx(value, &base);
-        if indices.is_err() {
-            return indices.map(|_| "".into());
+        Self::into_string(Self::decode_to_bytes(value, base)?)
+    }
+
+    /// same as the decode function but returns the raw decoded bytes instead of a string
+    /// this is the one to use when the encoded data is not valid utf8
+    ///
+    /// # Error
+    /// returns an Err when the passed encoded string and encoding base do not match
+    pub fn decode_to_bytes(value: impl AsRef<str>, base: Base) -> Result<Vec<u8>, DecodeError> {
+        let mut decoded = Vec::new();
+        Self::decode_into(value, base, &mut decoded)?;
+
+        Ok(decoded)
+    }
+
+    /// same as the decode_to_bytes function but the decoded bytes are written at the end of
+    /// the passed Vec, which makes it possible to reuse its capacity across many values
+    ///
+    /// ```
+    /// use makura::{BASE64, Decoder};
+    ///
+    /// let mut body = b"data: ".to_vec();
+    /// Decoder::decode_into("Zm9vYg==", BASE64, &mut body).unwrap();
+    /// assert_eq!(body, b"data: foob");
+    /// ```
+    ///
+    /// # Error
+    /// returns an Err when the passed encoded string and encoding base do not match,
+    /// the existing content of the Vec is left untouched either way
+    pub fn decode_into(
+        value: impl AsRef<str>,
+        base: Base,
+        decoded: &mut Vec<u8>,
+    ) -> Result<(), DecodeError> {
+        let value = value.as_ref();
+        if value.is_empty() {
+            return Ok(());
         }
-        let indices = indices.unwrap();
-
-        Self::into_string(match base {
-            BASE64 => base64_decode(indices),
-            BASE64URL => base64_url_decode(indices),
-            BASE45 => base45_decode(indices),
-            BASE32 => base32_decode(indices),
-            BASE32HEX => base32_hex_decode(indices),
-            BASE16 => base16_decode(indices),
-        })
+
+        let start = decoded.len();
+        Self::decode_append(value, &base, decoded).inspect_err(|_| decoded.truncate(start))
+    }
+
+    /// same as the decode_to_bytes function but meant for secrets, e.g., private keys
+    ///
+    /// the decoded bytes are wiped once the returned value is dropped,
+    /// and so are the intermediate buffers that the decoding goes through
+    ///
+    /// # Error
+    /// returns an Err when the passed encoded string and encoding base do not match
+    #[cfg(feature = "zeroize")]
+    pub fn decode_secret(
+        value: impl AsRef<str>,
+        base: Base,
+    ) -> Result<zeroize::Zeroizing<Vec<u8>>, DecodeError> {
+        let value = value.as_ref();
+        // no base decodes into more bytes than it has chars, except for the ascii85 'z'
+        // that stands for 4 zero bytes, the 4 extra bytes cover the reserve of a final
+        // partial block, so the output is never reallocated,
+        // which would leave a copy of the decoded bytes behind
+        let zeros = match base {
+            BASE85ASCII => value.bytes().filter(|b| *b == b'z').count(),
+            _ => 0,
+        };
+        let mut decoded = zeroize::Zeroizing::new(Vec::with_capacity(value.len() + zeros * 3 + 4));
+        Self::decode_into(value, base, &mut decoded)?;
+
+        Ok(decoded)
+    }
+
+    /// lazily decodes the chars of an iterator, the decoded bytes are produced as they are read
+    ///
+    /// see the `iter` module for how much of the input is read at a time
+    ///
+    /// # Error
+    /// yields a single Err when the chars and the encoding base do not match, then ends
+    pub fn decode_iter<I: IntoIterator<Item = char>>(
+        value: I,
+        base: Base,
+    ) -> DecodeIter<I::IntoIter> {
+        DecodeIter::new(value.into_iter(), base)
+    }
+
+    /// same as the decode_to_bytes function but the value is split on block boundaries
+    /// and the blocks are decoded in parallel on rayon's global thread pool
+    ///
+    /// only the [block bases](Base::is_block) are split; the other bases,
+    /// and values that are too small to be worth splitting, are decoded on the current thread
+    ///
+    /// # Error
+    /// returns an Err when the passed encoded string and encoding base do not match
+    #[cfg(feature = "rayon")]
+    pub fn decode_to_bytes_par(value: impl AsRef<str>, base: Base) -> Result<Vec<u8>, DecodeError> {
+        use rayon::prelude::*;
+
+        let value = value.as_ref();
+        let Some((bytes, block)) = base.block_len() else {
+            return Self::decode_to_bytes(value, base);
+        };
+        let chunk_len = PAR_CHUNK_LEN / block * block;
+        // chunks are cut at byte offsets, which are only char boundaries for ascii values
+        if value.len() <= chunk_len || !value.is_ascii() {
+            return Self::decode_to_bytes(value, base);
+        }
+
+        // every chunk but the last one is made of whole blocks without padding,
+        // so they all decode into the same count of bytes;
+        // the output is sized once and every chunk is copied into its own part of it
+        let (head, tail) = value.split_at((value.len() - 1) / chunk_len * chunk_len);
+        let decoded_len = chunk_len / block * bytes;
+        let mut decoded = Vec::with_capacity(
+            head.len() / chunk_len * decoded_len + tail.len() / block * bytes + bytes,
+        );
+        decoded.resize(head.len() / chunk_len * decoded_len, 0);
+        decoded
+            .par_chunks_mut(decoded_len)
+            .zip(head.as_bytes().par_chunks(chunk_len))
+            .enumerate()
+            .try_for_each_init(Vec::new, |buf, (idx, (out, chunk))| {
+                // a padded chunk has to be the last one, see Base::is_block
+                if base
+                    .pad_char()
+                    .is_some_and(|pad| chunk.ends_with(&[pad as u8]))
+                {
+                    return Err(DecodeError::BadEncodedString);
+                }
+
+                // the chunk is ascii, it was cut on a char boundary
+                let chunk =
+                    core::str::from_utf8(chunk).map_err(|_| DecodeError::BadEncodedString)?;
+                buf.clear();
+                Self::decode_chunk(chunk, &base, idx * chunk_len, buf)?;
+                if buf.len() != out.len() {
+                    return Err(DecodeError::BadEncodedString);
+                }
+                out.copy_from_slice(buf);
+
+                Ok(())
+            })?;
+        Self::decode_chunk(tail, &base, head.len(), &mut decoded)?;
+
+        Ok(decoded)
+    }
+
+    /// decodes the value in place, the decoded bytes overwrite the start of the buffer
+    /// and the returned slice is the part of the buffer that holds them
+    ///
+    /// the decoded bytes of a block never outnumber its chars, so nothing is allocated
+    ///
+    /// ```
+    /// use makura::{BASE64, Decoder};
+    ///
+    /// let mut request = *b"Zm9vYg==";
+    /// assert_eq!(Decoder::decode_in_place(&mut request, BASE64).unwrap(), b"foob");
+    /// ```
+    ///
+    /// # Error
+    /// returns an Err when
+    /// * the buffer holds bytes that are not in the base's table
+    /// * the base is not one of the [block bases](Base::is_block)
+    ///
+    /// the buffer may hold some decoded bytes over its start when an error is returned
+    pub fn decode_in_place(value: &mut [u8], base: Base) -> Result<&[u8], DecodeError> {
+        let Some((_, block)) = base.block_len() else {
+            return Err(DecodeError::UnsupportedForBase(base));
+        };
+        let mut len = value.len();
+        if let Some(pad) = base.pad_char() {
+            while len > 0 && value[len - 1] == pad as u8 {
+                len -= 1;
+            }
+        }
+        Self::check_len(len, &base)?;
+
+        // every block is read whole before its bytes are written,
+        // and the write offset never passes the read offset
+        let mut written = 0;
+        for start in (0..len).step_by(block) {
+            let chars = &value[start..len.min(start + block)];
+            let mut indices = [0u8; 8];
+            for (idx, b) in indices.iter_mut().zip(chars) {
+                *idx =
+                    idx_from_char(*b as char, &base).map_err(|_| DecodeError::BadEncodedString)?;
+            }
+            let (bytes, count) = Self::decode_block(&indices[..chars.len()], &base, start / block)?;
+            value[written..written + count].copy_from_slice(&bytes[..count]);
+            written += count;
+        }
+
+        Ok(&value[..written])
+    }
+
+    // turns the table indices of a single block back into its bytes,
+    // returns them along with the count of bytes that the block holds
+    fn decode_block(
+        indices: &[u8],
+        base: &Base,
+        i: usize,
+    ) -> Result<([u8; 5], usize), DecodeError> {
+        let mut bytes = [0u8; 5];
+        let count = match *base {
+            #[cfg(feature = "base64_crypt")]
+            BASE64CRYPT => {
+                bytes[..3].copy_from_slice(&base64::into_8bits_values_crypt(indices));
+                indices.len() * 6 / 8
+            }
+            _ if base.is_any_64() || base.is_64_bcrypt() => {
+                bytes[..3].copy_from_slice(&base64::into_8bits_values(indices));
+                indices.len() * 6 / 8
+            }
+            BASE32 | BASE32HEX | BASE32NSEC3 | BASE32Z => {
+                bytes = base32::into_8bits_chunk(indices);
+                indices.len() * 5 / 8
+            }
+            BASE16 => {
+                bytes[0] = indices[0] << 4 | indices[1];
+                1
+            }
+            BASE45 => {
+                let (pair, count) = into_8bits_pair(indices, i)?;
+                bytes[..2].copy_from_slice(&pair);
+                count
+            }
+            BASE85 | BASE85Z => {
+                let (quad, count) = into_8bits_quad(indices, i)?;
+                bytes[..4].copy_from_slice(&quad);
+                count
+            }
+            _ => return Err(DecodeError::UnsupportedForBase(*base)),
+        };
+
+        Ok((bytes, count))
     }
 
     /// same as the decode function  but takes and returns raw Vec<u8>s instead of string types
@@ -146,22 +494,105 @@ impl Decoder {
             return correct_base.map(|_| Vec::new());
         }
 
-        Ok(match base {
-            BASE64 => base64_decode(value),
-            BASE64URL => base64_url_decode(value),
-            BASE45 => base45_decode(value),
-            BASE32 => base32_decode(value),
-            BASE32HEX => base32_hex_decode(value),
-            BASE16 => base16_decode(value),
-        })
+        let mut decoded = Vec::new();
+        Self::decode_indices(&value, &base, &mut decoded)?;
+
+        Ok(decoded)
+    }
+
+    // a final partial block of these lengths can't be made of whole bytes,
+    // no encoder produces it, so the value was truncated or corrupted
+    fn check_len(len: usize, base: &Base) -> Result<(), DecodeError> {
+        let bad = match *base {
+            _ if base.is_any_64() || base.is_64_bcrypt() || base.is_64_crypt() => len % 4 == 1,
+            BASE32 | BASE32HEX | BASE32NSEC3 | BASE32Z => matches!(len % 8, 1 | 3 | 6),
+            BASE16 => len % 2 == 1,
+            BASE45 => len % 3 == 1,
+            BASE85 | BASE85ASCII => len % 5 == 1,
+            // z85 has no partial groups at all
+            BASE85Z => len % 5 != 0,
+            _ => false,
+        };
+
+        match bad {
+            true => Err(DecodeError::BadLenForBase(len)),
+            false => Ok(()),
+        }
+    }
+
+    // turns table indices back into the original bytes, written at the end of the output
+    fn decode_indices(indices: &[u8], base: &Base, bytes: &mut Vec<u8>) -> Result<(), DecodeError> {
+        Self::check_len(indices.len(), base)?;
+        match *base {
+            BASE64 => base64_decode(indices, bytes),
+            BASE64URL => base64_url_decode(indices, bytes),
+            BASE64CRYPT => base64_crypt_decode(indices, bytes),
+            BASE64BCRYPT | BASE64IMAP | BASE64XMLNAME | BASE64XMLNMTOKEN | BASE64Y64 => {
+                base64_profile_decode(indices, bytes)
+            }
+            BASE45 => base45_decode(indices, bytes)?,
+            BASE32 => base32_decode(indices, bytes),
+            BASE32HEX => base32_hex_decode(indices, bytes),
+            BASE32NSEC3 => base32_nsec3_decode(indices, bytes),
+            BASE32Z => base32_z_decode(indices, bytes),
+            BASE16 => base16_decode(indices, bytes),
+            BASE36 => base36_decode(indices, bytes),
+            BASE58 => base58_decode(indices, bytes),
+            BASE62 => base62_decode(indices, bytes),
+            BASE85 | BASE85ASCII | BASE85Z => base85_decode(indices, bytes)?,
+            BASE91 => base91_decode(indices, bytes),
+        }
+
+        Ok(())
+    }
+
+    /// decodes the first `bits` bits of a given string
+    /// this is the counterpart of the `Encoder::encode_bits` method
+    ///
+    /// returns the decoded bytes, with the bits that come after the bit count zeroed out
+    ///
+    /// # Error
+    /// returns an Err when
+    /// * the passed encoded string and encoding base do not match
+    /// * the encoded string does not hold as many as `bits` bits
+    pub fn decode_bits(
+        value: impl AsRef<str>,
+        base: Base,
+        bits: usize,
+    ) -> Result<Vec<u8>, DecodeError> {
+        let value = value.as_ref();
+        let mut indices = Self::into_table_idx(value, &base)?;
+
+        if base == BASE32Z {
+            if bits > indices.len() * 5 {
+                crate::wipe(&mut indices);
+                return Err(DecodeError::BadLenForBase(value.len()));
+            }
+
+            let bytes = base32_z_decode_bits(&indices, bits);
+            crate::wipe(&mut indices);
+
+            return Ok(bytes);
+        }
+
+        let mut bytes = Vec::new();
+        let res = Self::decode_indices(&indices, &base, &mut bytes);
+        crate::wipe(&mut indices);
+        res?;
+        if bits > bytes.len() * 8 {
+            return Err(DecodeError::BadLenForBase(value.len()));
+        }
+        crate::truncate_bits(&mut bytes, bits);
+
+        Ok(bytes)
     }
 
     /// asserts that the given vec of bytes is encoded with the given base
     pub fn assert_encoding(value: &[u8], base: &Base) -> Result<(), DecodeError> {
-        let max = *value.into_iter().max().unwrap();
+        let max = *value.iter().max().unwrap();
         let len = value.len();
         match base {
-            Base::_64 | Base::_64URL => {
+            Base::_64 | Base::_64URL | Base::_64Y64 => {
                 if max < 64 && len % 4 == 0 {
                     Ok(())
                 } else if len % 4 == 0 {
@@ -179,6 +610,28 @@ impl Decoder {
                     Err(DecodeError::BadLenForBase(len))
                 }
             }
+            // the unpadded base 64 profiles, a final partial group can not be a single char long
+            Base::_64BCRYPT
+            | Base::_64CRYPT
+            | Base::_64IMAP
+            | Base::_64XMLNAME
+            | Base::_64XMLNMTOKEN => {
+                if max < 64 && len % 4 != 1 {
+                    Ok(())
+                } else if len % 4 != 1 {
+                    Err(DecodeError::TableIndexOverflow(max))
+                } else {
+                    Err(DecodeError::BadLenForBase(len))
+                }
+            }
+            // z-base-32 and nsec3 have no padding, any length is valid
+            Base::_32Z | Base::_32NSEC3 => {
+                if max < 32 {
+                    Ok(())
+                } else {
+                    Err(DecodeError::TableIndexOverflow(max))
+                }
+            }
             Base::_32 | Base::_32HEX => {
                 if max < 32 && len % 8 == 0 {
                     Ok(())
@@ -188,6 +641,47 @@ impl Decoder {
                     Err(DecodeError::BadLenForBase(len))
                 }
             }
+            // base 36, 58 and 62 have no padding, any length is valid
+            Base::_36 | Base::_58 | Base::_62 => {
+                let size = match base {
+                    Base::_36 => 36,
+                    Base::_58 => 58,
+                    _ => 62,
+                };
+                if max < size {
+                    Ok(())
+                } else {
+                    Err(DecodeError::TableIndexOverflow(max))
+                }
+            }
+            // z85 has no partial groups at all
+            Base::_85Z => {
+                if max < 85 && len % 5 == 0 {
+                    Ok(())
+                } else if len % 5 == 0 {
+                    Err(DecodeError::TableIndexOverflow(max))
+                } else {
+                    Err(DecodeError::BadLenForBase(len))
+                }
+            }
+            // base 85 has no padding, a final partial group can not be a single char long
+            Base::_85 | Base::_85ASCII => {
+                if max < 85 && len % 5 != 1 {
+                    Ok(())
+                } else if len % 5 != 1 {
+                    Err(DecodeError::TableIndexOverflow(max))
+                } else {
+                    Err(DecodeError::BadLenForBase(len))
+                }
+            }
+            // base 91 has no padding, any length is valid
+            Base::_91 => {
+                if max < 91 {
+                    Ok(())
+                } else {
+                    Err(DecodeError::TableIndexOverflow(max))
+                }
+            }
             Base::_16 => {
                 if max < 16 && len % 2 == 0 {
                     Ok(())
@@ -216,25 +710,20 @@ impl Decoder {
         if value.is_empty() {
             return Ok("".into());
         }
-        let base = match Self::deduce_encoding(value) {
-            Err(e) => return Err(e),
-            Ok(b) => b,
-        };
+        let base = Self::deduce_encoding(value)?;
 
         let indices = Self::into_table_idx(value, &base);
         if indices.is_err() {
             return indices.map(|_| "".into());
         }
-        let indices = indices.unwrap();
-
-        Self::into_string(match base {
-            BASE64 => base64_decode(indices),
-            BASE64URL => base64_url_decode(indices),
-            BASE45 => base45_decode(indices),
-            BASE32 => base32_decode(indices),
-            BASE32HEX => base32_hex_decode(indices),
-            BASE16 => base16_decode(indices),
-        })
+        let mut indices = indices.unwrap();
+
+        let mut decoded = Vec::new();
+        let res = Self::decode_indices(&indices, &base, &mut decoded);
+        crate::wipe(&mut indices);
+        res?;
+
+        Self::into_string(decoded)
     }
 
     // deduction methods
@@ -275,16 +764,16 @@ impl Decoder {
             });
         } else if value
             .chars()
-            .all(|c| ('0'..='9').contains(&c) || ('A'..='F').contains(&c))
+            .all(|c: char| c.is_ascii_digit() || ('A'..='F').contains(&c))
         {
             if len % 2 == 0 {
                 return Ok(BASE16);
             } else if len % 3 == 1 {
                 return Err(DecodeError::BadEncodedString);
             }
-        } else if value.chars().all(|c| {
-            ('0'..='9').contains(&c)
-                || ('A'..='Z').contains(&c)
+        } else if value.chars().all(|c: char| {
+            c.is_ascii_digit()
+                || c.is_ascii_uppercase()
                 || [' ', '$', '%', '*', '+', '-', '.', '/', ':'].contains(&c)
         }) {
             let residual = len % 3;
@@ -298,7 +787,7 @@ impl Decoder {
         // should have been if else not if
         if value
             .chars()
-            .all(|c| ('0'..='9').contains(&c) || ('A'..='V').contains(&c) || c == '=')
+            .all(|c: char| c.is_ascii_digit() || ('A'..='V').contains(&c) || c == '=')
         {
             return Ok(BASE32HEX);
         } else if !value.contains(['0', '1', '8', '9'])
@@ -331,7 +820,7 @@ impl Decoder {
             };
         } else if value
             .chars()
-            .all(|c| ('0'..='9').contains(&c) || ('A'..='F').contains(&c))
+            .all(|c: char| c.is_ascii_digit() || ('A'..='F').contains(&c))
             && !exclude.is_excluded(&BASE16)
         {
             if len % 2 == 0 {
@@ -339,9 +828,9 @@ impl Decoder {
             } else if len % 3 == 1 {
                 return Err(DecodeError::BadEncodedString);
             }
-        } else if value.chars().all(|c| {
-            ('0'..='9').contains(&c)
-                || ('A'..='Z').contains(&c)
+        } else if value.chars().all(|c: char| {
+            c.is_ascii_digit()
+                || c.is_ascii_uppercase()
                 || [' ', '$', '%', '*', '+', '-', '.', '/', ':'].contains(&c)
         }) && !exclude.is_excluded(&BASE45)
         {
@@ -356,7 +845,7 @@ impl Decoder {
         // should have been if else not if
         if value
             .chars()
-            .all(|c| ('0'..='9').contains(&c) || ('A'..='V').contains(&c) || c == '=')
+            .all(|c: char| c.is_ascii_digit() || ('A'..='V').contains(&c) || c == '=')
             && !exclude.is_excluded(&BASE32HEX)
         {
             return Ok(BASE32HEX);
@@ -370,39 +859,36 @@ impl Decoder {
     }
 }
 
-trait BaseExclusion {
-    fn is_excluded(&self, base: &Base) -> bool;
+// the trait is public only so it can bound deduce_exclude, the module keeps it unnameable
+// outside of the crate
+mod exclusion {
+    use super::Base;
+    use crate::makura_alloc::Vec;
 
-    fn are_excluded(&self, bases: &[Base]) -> bool;
+    pub trait BaseExclusion {
+        fn is_excluded(&self, base: &Base) -> bool;
 
-    fn is_single(&self) -> bool;
-}
-
-impl BaseExclusion for Base {
-    fn is_excluded(&self, base: &Base) -> bool {
-        self == base
-    }
-
-    fn are_excluded(&self, _: &[Base]) -> bool {
-        false
+        fn are_excluded(&self, bases: &[Base]) -> bool;
     }
 
-    fn is_single(&self) -> bool {
-        true
-    }
-}
+    impl BaseExclusion for Base {
+        fn is_excluded(&self, base: &Base) -> bool {
+            self == base
+        }
 
-impl BaseExclusion for Vec<Base> {
-    fn is_excluded(&self, base: &Base) -> bool {
-        self.contains(base)
+        fn are_excluded(&self, _: &[Base]) -> bool {
+            false
+        }
     }
 
-    fn are_excluded(&self, bases: &[Base]) -> bool {
-        bases.into_iter().all(|b| self.contains(b))
-    }
+    impl BaseExclusion for Vec<Base> {
+        fn is_excluded(&self, base: &Base) -> bool {
+            self.contains(base)
+        }
 
-    fn is_single(&self) -> bool {
-        false
+        fn are_excluded(&self, bases: &[Base]) -> bool {
+            bases.iter().all(|b| self.contains(b))
+        }
     }
 }
 
@@ -1,85 +1,71 @@
-#![cfg(any(feature = "base32", feature = "base32_hex"))]
+#![cfg(any(
+    feature = "base32",
+    feature = "base32_hex",
+    feature = "base32_nsec3",
+    feature = "base32_z",
+    feature = "bech32"
+))]
 use crate::makura_alloc::Vec;
 
-use super::DecodeError;
+/// regroups a chunk of up to 8 table indices of 5 bits back into 5 bytes,
+/// the missing indices of a final partial chunk are zero filled
+pub(crate) fn into_8bits_chunk(chunk: &[u8]) -> [u8; 5] {
+    let b = |i: usize| chunk.get(i).copied().unwrap_or(0);
+    let [b0, b1, b2, b3, b4, b5, b6, b7] = core::array::from_fn(b);
 
-/// DOCS
-/// last 3 octets
-/// (1) The final quantum of encoding input is an integral multiple of 24
-///     bits; here, the final unit of encoded output will be an integral
-///     multiple of 4 characters with no "=" padding.
-///
-/// (2) The final quantum of encoding input is exactly 8 bits; here, the
-///     final unit of encoded output will be two characters followed by
-///     two "=" padding characters.
-///
-/// (3) The final quantum of encoding input is exactly 16 bits; here, the
-///     final unit of encoded output will be three characters followed by
-///     one "=" padding character.
-
-// to implement the other decoders
-// only a different version of this function is needed
-// the other functions stay the same
-fn into_40bits_bytes(value: Vec<u8>) -> Vec<u64> {
-    // NOTE len must be an integra multiple of 4
-    value
-        .chunks(8)
-        .map(|b| {
-            let mut mask = 0u64;
-            mask |= b[0] as u64;
-            mask <<= 5;
-            mask |= b[1] as u64;
-            mask <<= 5;
-            mask |= b[2] as u64;
-            mask <<= 5;
-            mask |= b[3] as u64;
-            mask <<= 5;
-            mask |= b[4] as u64;
-            mask <<= 5;
-            mask |= b[5] as u64;
-            mask <<= 5;
-            mask |= b[6] as u64;
-            mask <<= 5;
-            mask |= b[7] as u64;
+    [
+        b0 << 3 | b1 >> 2,
+        b1 << 6 | b2 << 1 | b3 >> 4,
+        b3 << 4 | b4 >> 1,
+        b4 << 7 | b5 << 2 | b6 >> 3,
+        b6 << 5 | b7,
+    ]
+}
 
-            mask
-        })
-        .collect()
+// writes the bytes of every 8 indices chunk straight into the output,
+// the padding chars are never part of the indices,
+// so a final partial chunk of n indices decodes into its n * 5 / 8 whole bytes
+//
+// only whole bytes are decoded, the left over bits are the encoder's zero fill
+fn decode_5bits(indices: &[u8], bytes: &mut Vec<u8>) {
+    bytes.reserve(indices.len() / 8 * 5 + 4);
+    indices.chunks(8).for_each(|chunk| {
+        let len = chunk.len() * 5 / 8;
+        bytes.extend_from_slice(&into_8bits_chunk(chunk)[..len]);
+    });
 }
 
-// get back 8 bit bytes from the 24bits bytes
-fn into_8bits_bytes(value: Vec<u64>) -> Vec<u8> {
-    let mut bytes = value
-        .into_iter()
-        .map(|b| {
-            [
-                // same as ( b >> 32 ) as u8
-                ((b & 0xff00000000) >> 32) as u8,
-                ((b & 0xff000000) >> 24) as u8,
-                ((b & 0xff0000) >> 16) as u8,
-                ((b & 0xff00) >> 8) as u8,
-                b as u8,
-            ]
-        })
-        .flatten()
-        .collect::<Vec<u8>>();
-    while let Some(0) = bytes.last() {
-        bytes.pop();
-    }
+#[cfg(feature = "base32")]
+pub fn base32_decode(indices: &[u8], bytes: &mut Vec<u8>) {
+    decode_5bits(indices, bytes)
+}
 
-    bytes
+#[cfg(feature = "base32_hex")]
+pub fn base32_hex_decode(indices: &[u8], bytes: &mut Vec<u8>) {
+    decode_5bits(indices, bytes)
 }
 
-#[cfg(feature = "base32")]
-pub fn base32_decode(indices: Vec<u8>) -> Vec<u8> {
-    let bytes = into_40bits_bytes(indices);
+#[cfg(feature = "base32_nsec3")]
+pub fn base32_nsec3_decode(indices: &[u8], bytes: &mut Vec<u8>) {
+    decode_5bits(indices, bytes)
+}
 
-    into_8bits_bytes(bytes)
+#[cfg(feature = "base32_z")]
+pub fn base32_z_decode(indices: &[u8], bytes: &mut Vec<u8>) {
+    decode_5bits(indices, bytes)
 }
 
-#[cfg(feature = "base32_hex")]
-pub fn base32_hex_decode(indices: Vec<u8>) -> Vec<u8> {
-    let bytes = into_40bits_bytes(indices);
+// decodes the first `bits` bits of the indices, the bits that come after are zeroed
+// bits must not be greater than indices.len() * 5
+//
+// bech32 shares this regrouping
+#[cfg(any(feature = "base32_z", feature = "bech32"))]
+pub fn base32_z_decode_bits(indices: &[u8], bits: usize) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(bits.div_ceil(8).next_multiple_of(5));
+    indices[..bits.div_ceil(5)]
+        .chunks(8)
+        .for_each(|chunk| bytes.extend(into_8bits_chunk(chunk)));
+    crate::truncate_bits(&mut bytes, bits);
 
-    into_8bits_bytes(bytes)
+    bytes
 }
@@ -1,52 +1,28 @@
 #![cfg(feature = "base45")]
-use crate::makura_alloc::Vec;
-
 use super::DecodeError;
+use crate::makura_alloc::Vec;
 
-fn into_base45_values(bytes: Vec<u8>) -> Vec<u16> {
-    let mut chunks = bytes.chunks(3);
-    let last = chunks.next_back().unwrap();
-
-    let mut values: Vec<u16> = chunks
-        // .inspect(|c| println!("{:?}", c))
-        .map(|b| b[2] as u16 * 45 * 45 + b[1] as u16 * 45 + b[0] as u16)
-        .collect();
-
-    let last = {
-        if last.len() == 3 {
-            last[2] as u16 * 45 * 45 + last[1] as u16 * 45 + last[0] as u16
-        } else if last.len() == 2 {
-            last[1] as u16 * 45 + last[0] as u16
-        } else {
-            unreachable!("last chunk len can only be 2 or 3");
-        }
-    };
-    values.push(last);
-
-    values
-}
-
-// get back 8 bit bytes from the 24bits bytes
-fn into_base265_values(value: Vec<u16>) -> Vec<u8> {
-    let mut bytes = value.into_iter();
-    let last = bytes.next_back().unwrap();
-    let mut bytes = bytes
-        .map(|b| [((b & 0xff00) >> 8) as u8, b as u8])
-        .flatten()
-        .collect::<Vec<u8>>();
-
-    if last < u8::MAX as u16 {
-        bytes.push(last as u8);
-    } else {
-        bytes.push((last >> 8) as u8);
-        bytes.push(last as u8);
+/// turns a chunk of 3 digits, least significant digit first, back into its 16 bits value,
+/// a final chunk of 2 digits holds a single byte; i is the index of the chunk, for the overflow error
+///
+/// returns the bytes along with the count of them that the chunk holds
+pub(crate) fn into_8bits_pair(chunk: &[u8], i: usize) -> Result<([u8; 2], usize), DecodeError> {
+    let n = chunk.iter().rev().fold(0u32, |n, d| n * 45 + *d as u32);
+    match chunk.len() {
+        3 if n <= 0xffff => Ok(([(n >> 8) as u8, n as u8], 2)),
+        2 if n <= 0xff => Ok(([n as u8, 0], 1)),
+        _ => Err(DecodeError::Base45GroupOverflow(i)),
     }
-
-    bytes
 }
 
-pub fn base45_decode(indices: Vec<u8>) -> Vec<u8> {
-    let bytes = into_base45_values(indices);
+// every 3 digits chunk is written straight into the output as a 16 bits value,
+// a final 2 digits chunk holds a single byte
+pub fn base45_decode(indices: &[u8], bytes: &mut Vec<u8>) -> Result<(), DecodeError> {
+    bytes.reserve(indices.len() / 3 * 2 + 1);
+    for (i, chunk) in indices.chunks(3).enumerate() {
+        let (pair, len) = into_8bits_pair(chunk, i)?;
+        bytes.extend_from_slice(&pair[..len]);
+    }
 
-    into_base265_values(bytes)
+    Ok(())
 }
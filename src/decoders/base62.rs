@@ -0,0 +1,27 @@
+#![cfg(any(feature = "base36", feature = "base58", feature = "base62"))]
+use crate::makura_alloc::Vec;
+
+use crate::BigBaseTransformer;
+
+// transforms the big base 36/58/62 integer back into a base 256 integer, i.e., bytes
+fn into_base256_digits(indices: &[u8], base: u32, bytes: &mut Vec<u8>) {
+    let mut transformer = BigBaseTransformer::new(base, 256, indices);
+    transformer.transform_all();
+
+    bytes.extend_from_slice(transformer.sequence())
+}
+
+#[cfg(feature = "base36")]
+pub fn base36_decode(indices: &[u8], bytes: &mut Vec<u8>) {
+    into_base256_digits(indices, 36, bytes)
+}
+
+#[cfg(feature = "base58")]
+pub fn base58_decode(indices: &[u8], bytes: &mut Vec<u8>) {
+    into_base256_digits(indices, 58, bytes)
+}
+
+#[cfg(feature = "base62")]
+pub fn base62_decode(indices: &[u8], bytes: &mut Vec<u8>) {
+    into_base256_digits(indices, 62, bytes)
+}
@@ -1,75 +1,83 @@
-#![cfg(any(feature = "base64", feature = "base64_url"))]
+#![cfg(any(
+    feature = "base64",
+    feature = "base64_url",
+    feature = "base64_bcrypt",
+    feature = "base64_crypt",
+    feature = "base64_imap",
+    feature = "base64_xml_name",
+    feature = "base64_xml_nmtoken",
+    feature = "base64_y64",
+    feature = "uuencode"
+))]
 use crate::makura_alloc::Vec;
 
-use super::DecodeError;
+// DOCS
+// last 3 octets
+// (1) The final quantum of encoding input is an integral multiple of 24
+//     bits; here, the final unit of encoded output will be an integral
+//     multiple of 4 characters with no "=" padding.
+//
+// (2) The final quantum of encoding input is exactly 8 bits; here, the
+//     final unit of encoded output will be two characters followed by
+//     two "=" padding characters.
+//
+// (3) The final quantum of encoding input is exactly 16 bits; here, the
+//     final unit of encoded output will be three characters followed by
+//     one "=" padding character.
 
-/// DOCS
-/// last 3 octets
-/// (1) The final quantum of encoding input is an integral multiple of 24
-///     bits; here, the final unit of encoded output will be an integral
-///     multiple of 4 characters with no "=" padding.
-///
-/// (2) The final quantum of encoding input is exactly 8 bits; here, the
-///     final unit of encoded output will be two characters followed by
-///     two "=" padding characters.
-///
-/// (3) The final quantum of encoding input is exactly 16 bits; here, the
-///     final unit of encoded output will be three characters followed by
-///     one "=" padding character.
+/// regroups a chunk of up to 4 table indices of 6 bits back into 3 bytes,
+/// the missing indices of a final partial chunk are zero filled
+pub(crate) fn into_8bits_values(chunk: &[u8]) -> [u8; 3] {
+    let b = |i: usize| chunk.get(i).copied().unwrap_or(0);
+    let (b0, b1, b2, b3) = (b(0), b(1), b(2), b(3));
 
-// to implement the other decoders
-// only a different version of this function is needed
-// the other functions stay the same
-fn into_24bits_bytes(value: Vec<u8>) -> Vec<u32> {
-    // NOTE len must be an integra multiple of 4
-    value
-        .chunks(4)
-        // .inspect(|c| println!("{:?}", c))
-        .map(|b| {
-            let mut mask = 0u32;
-            mask |= b[0] as u32;
-            mask <<= 6;
-            mask |= b[1] as u32;
-            mask <<= 6;
-            mask |= b[2] as u32;
-            mask <<= 6;
-            mask |= b[3] as u32;
-
-            mask
-        })
-        .collect()
+    [b0 << 2 | b1 >> 4, b1 << 4 | b2 >> 2, b2 << 6 | b3]
 }
 
-// get back 8 bit bytes from the 24bits bytes
-fn into_8bits_bytes(value: Vec<u32>) -> Vec<u8> {
-    let mut bytes = value
-        .into_iter()
-        .map(|b| {
-            [
-                ((b & 0xff0000) >> 16) as u8,
-                ((b & 0xff00) >> 8) as u8,
-                b as u8,
-            ]
-        })
-        .flatten()
-        .collect::<Vec<u8>>();
-    while let Some(0) = bytes.last() {
-        bytes.pop();
-    }
+/// same as into_8bits_values but in crypt(3)'s order,
+/// the first index holds the lowest 6 bits of a little endian 24 bits number
+#[cfg(feature = "base64_crypt")]
+pub(crate) fn into_8bits_values_crypt(chunk: &[u8]) -> [u8; 3] {
+    let w = chunk.iter().rev().fold(0u32, |w, b| w << 6 | *b as u32);
 
-    bytes
+    [w as u8, (w >> 8) as u8, (w >> 16) as u8]
 }
 
-#[cfg(feature = "base64")]
-pub fn base64_decode(indices: Vec<u8>) -> Vec<u8> {
-    let bytes = into_24bits_bytes(indices);
+// writes the bytes of every 4 indices chunk straight into the output,
+// the padding chars are never part of the indices,
+// so a final partial chunk of n indices decodes into its n * 6 / 8 whole bytes
+fn decode_6bits(indices: &[u8], bytes: &mut Vec<u8>, regroup: fn(&[u8]) -> [u8; 3]) {
+    bytes.reserve(indices.len() / 4 * 3 + 2);
+    indices.chunks(4).for_each(|chunk| {
+        let len = chunk.len() * 6 / 8;
+        bytes.extend_from_slice(&regroup(chunk)[..len]);
+    });
+}
 
-    into_8bits_bytes(bytes)
+#[cfg(feature = "base64")]
+pub fn base64_decode(indices: &[u8], bytes: &mut Vec<u8>) {
+    decode_6bits(indices, bytes, into_8bits_values)
 }
 
 #[cfg(feature = "base64_url")]
-pub fn base64_url_decode(indices: Vec<u8>) -> Vec<u8> {
-    let bytes = into_24bits_bytes(indices);
+pub fn base64_url_decode(indices: &[u8], bytes: &mut Vec<u8>) {
+    decode_6bits(indices, bytes, into_8bits_values)
+}
+
+// the base 64 profiles share the base 64 regrouping and only differ in their tables
+#[cfg(any(
+    feature = "base64_bcrypt",
+    feature = "base64_imap",
+    feature = "base64_xml_name",
+    feature = "base64_xml_nmtoken",
+    feature = "base64_y64"
+))]
+pub fn base64_profile_decode(indices: &[u8], bytes: &mut Vec<u8>) {
+    decode_6bits(indices, bytes, into_8bits_values)
+}
 
-    into_8bits_bytes(bytes)
+// crypt(3) only shares the length rules of base 64, its bytes are regrouped in its own order
+#[cfg(feature = "base64_crypt")]
+pub fn base64_crypt_decode(indices: &[u8], bytes: &mut Vec<u8>) {
+    decode_6bits(indices, bytes, into_8bits_values_crypt)
 }
@@ -0,0 +1,64 @@
+#![cfg(any(feature = "base85", feature = "base85_ascii", feature = "base85_z"))]
+use crate::makura_alloc::{String, Vec};
+
+use super::DecodeError;
+use crate::BASE85ASCII;
+
+/// strips the ascii85 framing off of the encoded value
+/// * the '<~' '~>' delimiters are optional, pdf streams for instance only carry the '~>' one
+/// * whitespace is ignored
+/// * every 'z' char is expanded back into the full group of zeros "!!!!!"
+#[cfg(feature = "base85_ascii")]
+pub fn ascii85_unframe(value: &str) -> Result<String, DecodeError> {
+    let value = value.trim();
+    let value = value.strip_prefix("<~").unwrap_or(value);
+    let value = value.strip_suffix("~>").unwrap_or(value);
+
+    // every 'z' char grows into 5 chars, the buffer never needs to be reallocated
+    let mut unframed = String::with_capacity(value.len() + value.matches('z').count() * 4);
+    for ch in value.chars().filter(|c| !c.is_whitespace()) {
+        if ch != 'z' {
+            unframed.push(ch);
+        } else if unframed.len() % 5 == 0 {
+            unframed.push_str("!!!!!");
+        } else {
+            // 'z' can only stand in for a whole group,
+            // the chars that were read so far are wiped along with the buffer
+            crate::wipe(&mut unframed.into_bytes());
+            return Err(DecodeError::UnrecognizedCharForBase {
+                ch,
+                base: BASE85ASCII,
+            });
+        }
+    }
+
+    Ok(unframed)
+}
+
+// every group of 5 digits is written straight into the output as 4 bytes,
+// a final partial group of n + 1 digits decodes into n bytes
+//
+// the last partial group is padded with the highest digit,
+// which rounds its value up so that the kept bytes come out right
+pub fn base85_decode(indices: &[u8], bytes: &mut Vec<u8>) -> Result<(), DecodeError> {
+    let len = indices.len();
+    bytes.reserve(len.div_ceil(5) * 4);
+    for (i, group) in indices.chunks(5).enumerate() {
+        let (value, kept) = into_8bits_quad(group, i)?;
+        bytes.extend_from_slice(&value[..kept]);
+    }
+
+    Ok(())
+}
+
+/// turns a group of up to 5 digits back into its 4 bytes, along with the count of them
+/// that the group holds; i is the index of the group, for the overflow error
+pub(crate) fn into_8bits_quad(group: &[u8], i: usize) -> Result<([u8; 4], usize), DecodeError> {
+    let value = (0..5).fold(0u64, |n, j| {
+        n * 85 + group.get(j).copied().unwrap_or(84) as u64
+    });
+    let value = u32::try_from(value).map_err(|_| DecodeError::Base85GroupOverflow(i))?;
+    let kept = if group.len() == 5 { 4 } else { group.len() - 1 };
+
+    Ok((value.to_be_bytes(), kept))
+}
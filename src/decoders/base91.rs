@@ -0,0 +1,32 @@
+#![cfg(feature = "base91")]
+use crate::makura_alloc::Vec;
+
+// reverses the 13/14 bits grouping of the encoder
+// every pair of digits is turned back into a value, whose low 13 bits tell
+// whether 13 or 14 bits were taken to make it
+fn into_8bits_bytes(indices: &[u8], bytes: &mut Vec<u8>) {
+    bytes.reserve(indices.len() * 13 / 16 + 1);
+    let mut queue = 0u32;
+    let mut bits = 0u32;
+
+    let mut pairs = indices.chunks_exact(2);
+    pairs.by_ref().for_each(|pair| {
+        let value = pair[0] as u32 + pair[1] as u32 * 91;
+        queue |= value << bits;
+        bits += if value & 8191 > 88 { 13 } else { 14 };
+        while bits > 7 {
+            bytes.push(queue as u8);
+            queue >>= 8;
+            bits -= 8;
+        }
+    });
+
+    // a single trailing digit holds the last byte
+    if let [last] = *pairs.remainder() {
+        bytes.push((queue | (last as u32) << bits) as u8);
+    }
+}
+
+pub fn base91_decode(indices: &[u8], bytes: &mut Vec<u8>) {
+    into_8bits_bytes(indices, bytes)
+}
@@ -0,0 +1,116 @@
+#![cfg(feature = "encoding")]
+//! `Display` wrappers that write the encoding of a value straight into a formatter
+//!
+//! logging a hash or an id through one of these doesn't allocate a String for the encoded value
+//!
+//! ```
+//! use makura::display::{Base16, Base64};
+//!
+//! let hash = [0xca, 0xfe, 0xba, 0xbe];
+//! assert_eq!(format!("{}", Base16(&hash)), "CAFEBABE");
+//! assert_eq!(format!("{:x}", Base16(&hash)), "cafebabe");
+//! assert_eq!(format!("{}", Base64(b"foob")), "Zm9vYg==");
+//! ```
+//!
+//! the width, fill and alignment flags of the formatter are not applied to the output
+use core::fmt;
+
+use crate::Encoder;
+
+/// the encoding of a value under any base, returned by `Encoder::display`
+///
+/// * `{}` writes the encoding the same way that the encoder's encode method would
+/// * `{:x}` writes the base 16, base 32, base 32 hex and base 36 tables in lowercase
+/// * `{:X}` writes them in uppercase, even if the encoder was made lowercase
+///
+/// the other bases have tables that are not case insensitive, they ignore the case selection
+pub struct Display<'a> {
+    encoder: Encoder,
+    value: &'a [u8],
+}
+
+impl<'a> Display<'a> {
+    pub(crate) fn new(encoder: Encoder, value: &'a [u8]) -> Self {
+        Self { encoder, value }
+    }
+}
+
+impl fmt::Display for Display<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.encoder
+            .encode_fmt(self.value, f, self.encoder.is_lowercase())
+    }
+}
+
+impl fmt::LowerHex for Display<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.encoder.encode_fmt(self.value, f, true)
+    }
+}
+
+impl fmt::UpperHex for Display<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.encoder.encode_fmt(self.value, f, false)
+    }
+}
+
+impl fmt::Debug for Display<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{self}\"")
+    }
+}
+
+// shorthands for the bases that logs use the most
+macro_rules! display_base {
+    ($(#[$attr:meta])* $name:ident, $feature:literal, $encoder:ident) => {
+        $(#[$attr])*
+        #[cfg(feature = $feature)]
+        pub struct $name<'a>(pub &'a [u8]);
+
+        #[cfg(feature = $feature)]
+        impl fmt::Display for $name<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&Encoder::$encoder().display(self.0), f)
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl fmt::LowerHex for $name<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::LowerHex::fmt(&Encoder::$encoder().display(self.0), f)
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl fmt::UpperHex for $name<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::UpperHex::fmt(&Encoder::$encoder().display(self.0), f)
+            }
+        }
+    };
+}
+
+display_base!(
+    /// displays the bytes as base 16, uppercase by default, `{:x}` makes it lowercase
+    Base16,
+    "base16",
+    base16
+);
+display_base!(
+    /// displays the bytes as base 32, uppercase by default, `{:x}` makes it lowercase
+    Base32,
+    "base32",
+    base32
+);
+display_base!(
+    /// displays the bytes as padded base 64
+    Base64,
+    "base64",
+    base64
+);
+display_base!(
+    /// displays the bytes as padded base 64 url
+    Base64Url,
+    "base64_url",
+    base64_url
+);
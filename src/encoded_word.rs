@@ -0,0 +1,268 @@
+#![cfg(feature = "encoded_word")]
+//! [RFC 2047](https://datatracker.ietf.org/doc/html/rfc2047) mime encoded-words,
+//! the non ascii text of mail headers such as Subject, From and To
+//!
+//! ```text
+//! =?<charset>?<encoding>?<encoded text>?=
+//! ```
+//!
+//! e.g., "=?UTF-8?B?wqFIb2xhIQ==?=" or "=?UTF-8?Q?=C2=A1Hola!?="
+//!
+//! the "B" encoding is base64 and the "Q" encoding is a quoted-printable dialect
+//! where '_' stands for a space
+use crate::makura_alloc::{String, Vec};
+
+use crate::{BASE64, DecodeError, Decoder, Encoder};
+
+/// an encoded-word can't be longer than this
+const MAX_LEN: usize = 75;
+
+/// the charset that the encoder writes, the encoded text is always utf8
+const CHARSET: &str = "UTF-8";
+
+/// the encoding of an encoded-word's text
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum WordEncoding {
+    /// base64
+    B,
+    /// the quoted-printable dialect, '_' is a space and other special chars are =XX escaped
+    Q,
+}
+
+impl WordEncoding {
+    fn as_char(&self) -> char {
+        match self {
+            Self::B => 'B',
+            Self::Q => 'Q',
+        }
+    }
+}
+
+/// errors that can occur while decoding a header that contains encoded-words
+#[derive(Debug)]
+pub enum EncodedWordError {
+    /// the encoded-word's charset is not one of utf-8, us-ascii or iso-8859-1
+    /// .0 corresponds to the unsupported charset
+    UnsupportedCharset(String),
+    /// the encoded-word's encoding is neither "B" nor "Q"
+    /// .0 corresponds to the unknown encoding
+    UnknownEncoding(String),
+    /// a "Q" encoded-word contains an '=' that is not followed by 2 hex digits
+    /// .0 corresponds to the bad encoded text
+    BadQEncoding(String),
+    /// the text of a "B" encoded-word is not valid base64,
+    /// or the decoded text is not valid utf8
+    DecodeError(DecodeError),
+}
+
+// chars that a "Q" encoded-word can hold as they are anywhere in a header,
+// including in a phrase, which is the most restrictive place (rfc 2047 section 5)
+fn is_q_safe(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b"!*+-/".contains(&b)
+}
+
+fn q_encode_char(ch: char, encoded: &mut String) {
+    let mut buf = [0; 4];
+    ch.encode_utf8(&mut buf).bytes().for_each(|b| match b {
+        b' ' => encoded.push('_'),
+        b if is_q_safe(b) => encoded.push(b as char),
+        b => crate::hex_escape('=', b, encoded),
+    });
+}
+
+// the length that a char takes in the encoded text of a word
+fn encoded_len(ch: char, encoding: WordEncoding) -> usize {
+    match encoding {
+        WordEncoding::B => ch.len_utf8(),
+        WordEncoding::Q => {
+            let mut buf = [0; 4];
+            ch.encode_utf8(&mut buf)
+                .bytes()
+                .map(|b| if b == b' ' || is_q_safe(b) { 1 } else { 3 })
+                .sum()
+        }
+    }
+}
+
+/// encodes a header value into utf-8 encoded-words of at most 75 chars each
+///
+/// a value that doesn't fit into a single encoded-word is split between chars,
+/// never inside of a char's utf8 bytes, and the words are separated by "\r\n " folds
+///
+/// an empty value results in an empty string
+#[cfg(feature = "encoding")]
+pub fn encode(value: &str, encoding: WordEncoding) -> String {
+    // =?UTF-8?B??=
+    let room = MAX_LEN - CHARSET.len() - 7;
+    // 4 base64 chars for every 3 bytes
+    let room = match encoding {
+        WordEncoding::B => room / 4 * 3,
+        WordEncoding::Q => room,
+    };
+
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut len = 0;
+    for (idx, ch) in value.char_indices() {
+        let ch_len = encoded_len(ch, encoding);
+        if len + ch_len > room {
+            words.push(&value[start..idx]);
+            start = idx;
+            len = 0;
+        }
+        len += ch_len;
+    }
+    if start < value.len() {
+        words.push(&value[start..]);
+    }
+
+    let mut encoded = String::new();
+    words.into_iter().enumerate().for_each(|(idx, word)| {
+        if idx > 0 {
+            encoded.push_str("\r\n ");
+        }
+        encoded.push_str("=?");
+        encoded.push_str(CHARSET);
+        encoded.push('?');
+        encoded.push(encoding.as_char());
+        encoded.push('?');
+        match encoding {
+            WordEncoding::B => encoded.push_str(&Encoder::base64().encode(word)),
+            WordEncoding::Q => word.chars().for_each(|ch| q_encode_char(ch, &mut encoded)),
+        }
+        encoded.push_str("?=");
+    });
+
+    encoded
+}
+
+// a well formed encoded-word at the start of the value
+// returns its charset, encoding, encoded text and length
+fn parse_word(value: &str) -> Option<(&str, &str, &str, usize)> {
+    let rest = value.strip_prefix("=?")?;
+    let (charset, rest) = rest.split_once('?')?;
+    let (encoding, rest) = rest.split_once('?')?;
+    let text = &rest[..rest.find("?=")?];
+
+    let well_formed = !charset.is_empty()
+        && !encoding.is_empty()
+        && ![charset, encoding, text]
+            .iter()
+            .any(|part| part.contains(|c: char| c.is_ascii_whitespace()));
+
+    well_formed.then(|| {
+        let len = 2 + charset.len() + 1 + encoding.len() + 1 + text.len() + 2;
+
+        (charset, encoding, text, len)
+    })
+}
+
+fn q_decode(text: &str) -> Result<Vec<u8>, EncodedWordError> {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+
+    let mut idx = 0;
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'_' => decoded.push(b' '),
+            b'=' => {
+                let b = crate::hex_unescape(bytes, idx)
+                    .ok_or_else(|| EncodedWordError::BadQEncoding(text.into()))?;
+                decoded.push(b);
+                idx += 2;
+            }
+            b => decoded.push(b),
+        }
+        idx += 1;
+    }
+
+    Ok(decoded)
+}
+
+fn decode_text(encoding: &str, text: &str) -> Result<Vec<u8>, EncodedWordError> {
+    match encoding {
+        "B" | "b" => {
+            // some encoders drop the padding
+            let mut text = String::from(text);
+            let pad = text.len().next_multiple_of(4) - text.len();
+            text.extend((0..pad).map(|_| crate::PAD));
+
+            Decoder::decode_to_bytes(text, BASE64).map_err(EncodedWordError::DecodeError)
+        }
+        "Q" | "q" => q_decode(text),
+        _ => Err(EncodedWordError::UnknownEncoding(encoding.into())),
+    }
+}
+
+// turns the decoded bytes of one or more adjacent encoded-words into text
+fn into_text(bytes: Vec<u8>, charset: &str) -> Result<String, EncodedWordError> {
+    // rfc 2231 allows a language suffix, e.g., "UTF-8*en"
+    let charset = charset.split('*').next().unwrap_or_default();
+    if charset.eq_ignore_ascii_case("utf-8") || charset.eq_ignore_ascii_case("us-ascii") {
+        Decoder::into_string(bytes).map_err(EncodedWordError::DecodeError)
+    } else if charset.eq_ignore_ascii_case("iso-8859-1") {
+        // latin1 bytes are the first 256 unicode code points
+        Ok(bytes.into_iter().map(|b| b as char).collect())
+    } else {
+        Err(EncodedWordError::UnsupportedCharset(charset.into()))
+    }
+}
+
+/// decodes a header value that mixes plain text and encoded-words
+///
+/// the whitespace that separates 2 adjacent encoded-words is dropped
+/// and text that only looks like an encoded-word, e.g., "=?oops", is kept as it is
+///
+/// # Error
+/// returns an Err if an encoded-word's charset or encoding is not supported,
+/// or if its encoded text fails to decode
+#[cfg(feature = "decoding")]
+pub fn decode(value: &str) -> Result<String, EncodedWordError> {
+    let mut decoded = String::with_capacity(value.len());
+    // the bytes of the adjacent encoded-words that share a charset,
+    // a char can be split between them
+    let mut pending: Option<(&str, Vec<u8>)> = None;
+
+    // whether the text that is left comes right after an encoded-word
+    let mut after_word = false;
+
+    let mut rest = value;
+    while let Some(idx) = rest.find("=?") {
+        let Some((charset, encoding, text, len)) = parse_word(&rest[idx..]) else {
+            if let Some((charset, bytes)) = pending.take() {
+                decoded.push_str(&into_text(bytes, charset)?);
+            }
+            decoded.push_str(&rest[..idx + 2]);
+            rest = &rest[idx + 2..];
+            after_word = false;
+            continue;
+        };
+
+        let (before, word) = (&rest[..idx], decode_text(encoding, text)?);
+        // whitespace between 2 encoded-words is dropped
+        let adjacent = after_word && before.trim().is_empty();
+        match pending.as_mut() {
+            Some((last, bytes)) if adjacent && last.eq_ignore_ascii_case(charset) => {
+                bytes.extend(word)
+            }
+            _ => {
+                if let Some((charset, bytes)) = pending.take() {
+                    decoded.push_str(&into_text(bytes, charset)?);
+                }
+                if !adjacent {
+                    decoded.push_str(before);
+                }
+                pending = Some((charset, word));
+            }
+        }
+        rest = &rest[idx + len..];
+        after_word = true;
+    }
+
+    if let Some((charset, bytes)) = pending {
+        decoded.push_str(&into_text(bytes, charset)?);
+    }
+    decoded.push_str(rest);
+
+    Ok(decoded)
+}
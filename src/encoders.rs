@@ -1,65 +1,222 @@
 #![cfg(feature = "encoding")]
-use crate::makura_alloc::{String, Vec};
+use core::fmt;
+
+use crate::makura_alloc::String;
+#[cfg(feature = "rayon")]
+use crate::makura_alloc::Vec;
 
 use super::Base;
+use crate::DecodeError;
+use crate::display::Display;
+use crate::iter::EncodeIter;
 
 mod base16;
 mod base32;
 mod base45;
+mod base62;
 mod base64;
+mod base85;
+mod base91;
+mod sink;
 
 use base16::base16_encode;
 use base32::base32_encode;
 use base32::base32_hex_encode;
+use base32::base32_nsec3_encode;
+#[cfg(feature = "base32")]
+pub(crate) use base32::into_5bits_chunk;
+#[cfg(feature = "bech32")]
+pub(crate) use base32::into_5bits_values;
+use base32::{base32_z_encode, base32_z_encode_bits};
 use base45::base45_encode;
+use base62::{base36_encode, base58_encode, base62_encode};
 use base64::base64_encode;
+use base64::base64_profile_encode;
 use base64::base64_url_encode;
+#[cfg(any(feature = "uuencode", feature = "base64", feature = "base64_url"))]
+pub(crate) use base64::into_6bits_values;
+use base85::{base85_ascii_encode, base85_encode, base85_z_encode};
+use base91::base91_encode;
+pub(crate) use sink::{FmtSink, Lowercase, Sink};
+
+/// the rough number of input bytes that each thread of encode_par takes at a time
+#[cfg(feature = "rayon")]
+const PAR_CHUNK_LEN: usize = 1 << 16;
 
 /// exposes feature enabled bases encoding
+#[derive(Clone, Copy)]
 pub struct Encoder {
     base: Base,
+    lowercase: bool,
 }
 
 impl Encoder {
+    pub(crate) fn new(base: Base) -> Self {
+        Self {
+            base,
+            lowercase: false,
+        }
+    }
+
     /// returns the base of the encoder
     pub fn base(&self) -> &Base {
         &self.base
     }
 
+    // the lowercase flag only has an effect on the case insensitive tables
+    pub(crate) fn is_lowercase(&self) -> bool {
+        self.lowercase
+    }
+
+    /// makes the encoder output lowercase chars,
+    /// e.g., the lowercase hex that sha256sum or git produce
+    ///
+    /// only base 16, base 32, base 32 hex and base 36 have case insensitive tables,
+    /// for all other bases this has no effect
+    pub fn lowercase(mut self) -> Self {
+        self.lowercase = true;
+
+        self
+    }
+
     #[cfg(feature = "base64")]
     /// creates a new base64 encoder
     pub fn base64() -> Self {
-        Self { base: Base::_64 }
+        Self::new(Base::_64)
     }
 
     #[cfg(feature = "base64_url")]
     /// creates a new base64 url encoder
     pub fn base64_url() -> Self {
-        Self { base: Base::_64URL }
+        Self::new(Base::_64URL)
+    }
+
+    #[cfg(feature = "base64_bcrypt")]
+    /// creates a new bcrypt base64 encoder; './' then A-Z, a-z and 0-9, without padding
+    pub fn base64_bcrypt() -> Self {
+        Self::new(Base::_64BCRYPT)
+    }
+
+    #[cfg(feature = "base64_crypt")]
+    /// creates a new crypt(3) base64 encoder; './' then 0-9, A-Z and a-z, without padding
+    ///
+    /// every 3 bytes are read as a little endian number whose lowest 6 bits are written first,
+    /// as in md5-crypt and sha-crypt; the hashes' own shuffling of the digest bytes is not done
+    pub fn base64_crypt() -> Self {
+        Self::new(Base::_64CRYPT)
+    }
+
+    #[cfg(feature = "base64_imap")]
+    /// creates a new imap mailbox name base64 encoder (rfc 3501);
+    /// ',' takes the place of '/' and there is no padding
+    pub fn base64_imap() -> Self {
+        Self::new(Base::_64IMAP)
+    }
+
+    #[cfg(feature = "base64_xml_name")]
+    /// creates a new base64 encoder whose output is a valid xml Name;
+    /// '_' and ':' take the place of '+' and '/' and there is no padding
+    pub fn base64_xml_name() -> Self {
+        Self::new(Base::_64XMLNAME)
+    }
+
+    #[cfg(feature = "base64_xml_nmtoken")]
+    /// creates a new base64 encoder whose output is a valid xml Nmtoken;
+    /// '.' and '-' take the place of '+' and '/' and there is no padding
+    pub fn base64_xml_nmtoken() -> Self {
+        Self::new(Base::_64XMLNMTOKEN)
+    }
+
+    #[cfg(feature = "base64_y64")]
+    /// creates a new y64 encoder;
+    /// '.' and '_' take the place of '+' and '/' and '-' is the padding char
+    pub fn base64_y64() -> Self {
+        Self::new(Base::_64Y64)
     }
 
     #[cfg(feature = "base45")]
     /// creates a new base45 encoder
     pub fn base45() -> Self {
-        Self { base: Base::_45 }
+        Self::new(Base::_45)
     }
 
     #[cfg(feature = "base32")]
     /// creates a new base32 encoder
     pub fn base32() -> Self {
-        Self { base: Base::_32 }
+        Self::new(Base::_32)
     }
 
     #[cfg(feature = "base32_hex")]
     /// creates a new base32 hex encoder
     pub fn base32_hex() -> Self {
-        Self { base: Base::_32HEX }
+        Self::new(Base::_32HEX)
+    }
+
+    #[cfg(feature = "base32_nsec3")]
+    /// creates a new dnssec nsec3 encoder (rfc 5155), that is base32 hex in lowercase without padding
+    ///
+    /// the encoder is made lowercase, so `{:X}` still writes its display in uppercase
+    pub fn base32_nsec3() -> Self {
+        Self::new(Base::_32NSEC3).lowercase()
+    }
+
+    #[cfg(feature = "base32_z")]
+    /// creates a new z-base-32 encoder
+    pub fn base32_z() -> Self {
+        Self::new(Base::_32Z)
     }
 
     #[cfg(feature = "base16")]
     /// creates a new base16 encoder
     pub fn base16() -> Self {
-        Self { base: Base::_16 }
+        Self::new(Base::_16)
+    }
+
+    #[cfg(feature = "base36")]
+    /// creates a new base36 encoder
+    pub fn base36() -> Self {
+        Self::new(Base::_36)
+    }
+
+    #[cfg(feature = "base58")]
+    /// creates a new base58 encoder, this is the bitcoin table (base58btc)
+    pub fn base58() -> Self {
+        Self::new(Base::_58)
+    }
+
+    #[cfg(feature = "base62")]
+    /// creates a new base62 encoder
+    pub fn base62() -> Self {
+        Self::new(Base::_62)
+    }
+
+    #[cfg(feature = "base85")]
+    /// creates a new base85 encoder, this is the rfc 1924 alphabet that is used by git
+    pub fn base85() -> Self {
+        Self::new(Base::_85)
+    }
+
+    #[cfg(feature = "base85_ascii")]
+    /// creates a new ascii85 encoder, the encoded value is wrapped in '<~' '~>' delimiters
+    pub fn base85_ascii() -> Self {
+        Self::new(Base::_85ASCII)
+    }
+
+    #[cfg(feature = "base85_z")]
+    /// creates a new z85 encoder
+    ///
+    /// # Panic
+    /// z85 has no partial groups, encoding a value whose length is not a multiple of 4 panics;
+    /// `encode_to_fmt` and the display wrapper return a `fmt::Error` instead,
+    /// and `encode_iter` ends before the partial group, see `EncodeIter::error`
+    pub fn base85_z() -> Self {
+        Self::new(Base::_85Z)
+    }
+
+    #[cfg(feature = "base91")]
+    /// creates a new base91 encoder
+    pub fn base91() -> Self {
+        Self::new(Base::_91)
     }
 
     /// Apply self's base encoding to passed value argument.
@@ -69,13 +226,211 @@ impl Encoder {
     /// This method always returns a string,
     /// passing an empty string results in a an empty `String` return value
     pub fn encode(&self, value: impl AsRef<str>) -> String {
+        self.encode_bytes(value.as_ref().as_bytes())
+    }
+
+    /// same as the encode method but takes raw bytes instead of string types
+    /// this is the one to use for binary data that is not valid utf8
+    pub fn encode_bytes(&self, value: impl AsRef<[u8]>) -> String {
+        let mut encoded = String::new();
+        self.encode_into(value, &mut encoded);
+
+        encoded
+    }
+
+    /// same as the encode_bytes method but the encoded value is written at the end of the passed
+    /// String, which makes it possible to reuse its capacity across many values
+    ///
+    /// the existing content of the String is left untouched
+    ///
+    /// ```
+    /// use makura::Encoder;
+    ///
+    /// let mut body = String::from("{\"key\":\"");
+    /// Encoder::base64().encode_into(b"foob", &mut body);
+    /// body.push_str("\"}");
+    /// assert_eq!(body, "{\"key\":\"Zm9vYg==\"}");
+    /// ```
+    pub fn encode_into(&self, value: impl AsRef<[u8]>, encoded: &mut String) {
+        let value = value.as_ref();
+        let start = encoded.len();
+        self.encode_sink(value, encoded);
+        if self.lowercase && self.base.is_case_insensitive() {
+            encoded[start..].make_ascii_lowercase();
+        }
+    }
+
+    // writes the encoded value into any sink, a String takes the simd path when possible
+    pub(crate) fn encode_sink(&self, value: &[u8], encoded: &mut impl Sink) {
         match self.base {
-            Base::_64 => base64_encode(value),
-            Base::_64URL => base64_url_encode(value),
-            Base::_45 => base45_encode(value),
-            Base::_32 => base32_encode(value),
-            Base::_32HEX => base32_hex_encode(value),
-            Base::_16 => base16_encode(value),
+            Base::_64 => base64_encode(value, encoded),
+            Base::_64URL => base64_url_encode(value, encoded),
+            Base::_64BCRYPT
+            | Base::_64CRYPT
+            | Base::_64IMAP
+            | Base::_64XMLNAME
+            | Base::_64XMLNMTOKEN
+            | Base::_64Y64 => base64_profile_encode(value, &self.base, encoded),
+            Base::_45 => base45_encode(value, encoded),
+            Base::_32 => base32_encode(value, encoded),
+            Base::_32HEX => base32_hex_encode(value, encoded),
+            Base::_32NSEC3 => base32_nsec3_encode(value, encoded),
+            Base::_32Z => base32_z_encode(value, encoded),
+            Base::_16 => base16_encode(value, encoded),
+            Base::_36 => base36_encode(value, encoded),
+            Base::_58 => base58_encode(value, encoded),
+            Base::_62 => base62_encode(value, encoded),
+            Base::_85 => base85_encode(value, encoded),
+            Base::_85ASCII => base85_ascii_encode(value, encoded),
+            Base::_85Z => base85_z_encode(value, encoded),
+            Base::_91 => base91_encode(value, encoded),
+        }
+    }
+
+    /// writes the encoded value into any `fmt::Write`, e.g., a Formatter or a String
+    ///
+    /// the block bases are written without any allocation,
+    /// the big integer bases, base 36, base 58 and base 62, still need to hold their digits
+    ///
+    /// # Error
+    /// returns the error of the first write that fails, the chars that come after it are dropped;
+    /// a z85 value whose length is not a multiple of 4 fails before anything is written
+    pub fn encode_to_fmt(
+        &self,
+        value: impl AsRef<[u8]>,
+        out: &mut (impl fmt::Write + ?Sized),
+    ) -> fmt::Result {
+        self.encode_fmt(value.as_ref(), out, self.lowercase)
+    }
+
+    // writes the encoded value into a fmt::Write without any allocation,
+    // except for the big integer bases that need their digits before writing the first one
+    //
+    // the case insensitive tables are lowercased when lowercase is true
+    pub(crate) fn encode_fmt<W: fmt::Write + ?Sized>(
+        &self,
+        value: &[u8],
+        out: &mut W,
+        lowercase: bool,
+    ) -> fmt::Result {
+        if self.check_len(value.len()).is_err() {
+            return Err(fmt::Error);
+        }
+
+        let mut sink = FmtSink::new(out);
+        if lowercase && self.base.is_case_insensitive() {
+            self.encode_sink(value, &mut Lowercase(&mut sink));
+        } else {
+            self.encode_sink(value, &mut sink);
+        }
+
+        sink.res
+    }
+
+    /// wraps a value into a `Display` that writes the value's encoding straight into the formatter,
+    /// no String is allocated to hold the encoded value
+    ///
+    /// `{:x}` and `{:X}` select the case of the base 16, base 32, base 32 hex and base 36 output
+    ///
+    /// ```
+    /// use makura::Encoder;
+    ///
+    /// let hash = [0xde, 0xad, 0xbe, 0xef];
+    /// assert_eq!(format!("{}", Encoder::base16().display(&hash)), "DEADBEEF");
+    /// assert_eq!(format!("{:x}", Encoder::base16().display(&hash)), "deadbeef");
+    /// ```
+    pub fn display<'a>(&self, value: &'a (impl AsRef<[u8]> + ?Sized)) -> Display<'a> {
+        Display::new(*self, value.as_ref())
+    }
+
+    /// same as the encode_bytes method but the value is split on block boundaries
+    /// and the blocks are encoded in parallel on rayon's global thread pool
+    ///
+    /// the output is the same as the encode_bytes output
+    ///
+    /// only the [block bases](crate::Base::is_block) are split; the other bases,
+    /// and values that are too small to be worth splitting, are encoded on the current thread
+    #[cfg(feature = "rayon")]
+    pub fn encode_par(&self, value: impl AsRef<[u8]>) -> String {
+        use rayon::prelude::*;
+
+        let value = value.as_ref();
+        let Some((block, chars)) = self.base.block_len() else {
+            return self.encode_bytes(value);
+        };
+        let chunk_len = PAR_CHUNK_LEN / block * block;
+        if value.len() <= chunk_len {
+            return self.encode_bytes(value);
+        }
+
+        // only the last chunk can be a partial block, so the padding stays at the end;
+        // every other chunk encodes into the same count of chars,
+        // so the output is sized once and every chunk is copied into its own part of it
+        let (head, tail) = value.split_at((value.len() - 1) / chunk_len * chunk_len);
+        let encoded_len = chunk_len / block * chars;
+        let mut encoded = Vec::with_capacity(
+            head.len() / chunk_len * encoded_len + tail.len().div_ceil(block) * chars,
+        );
+        encoded.resize(head.len() / chunk_len * encoded_len, 0);
+        encoded
+            .par_chunks_mut(encoded_len)
+            .zip(head.par_chunks(chunk_len))
+            .for_each_init(String::new, |buf, (out, chunk)| {
+                buf.clear();
+                self.encode_into(chunk, buf);
+                out.copy_from_slice(buf.as_bytes());
+            });
+
+        // SAFETY: the encoding tables are all ascii
+        let mut encoded = unsafe { String::from_utf8_unchecked(encoded) };
+        self.encode_into(tail, &mut encoded);
+
+        encoded
+    }
+
+    // z85 has no partial groups, it can only encode whole chunks of 4 bytes;
+    // every other base can encode a value of any length
+    pub(crate) fn check_len(&self, len: usize) -> Result<(), DecodeError> {
+        match self.base == Base::_85Z && len % 4 != 0 {
+            true => Err(DecodeError::BadLenForBase(len)),
+            false => Ok(()),
         }
     }
+
+    /// lazily encodes the bytes of an iterator, the encoded chars are produced as they are read
+    ///
+    /// see the `iter` module for how much of the input is read at a time
+    pub fn encode_iter<I: IntoIterator<Item = u8>>(&self, value: I) -> EncodeIter<I::IntoIter> {
+        EncodeIter::new(*self, value.into_iter())
+    }
+
+    /// Encodes only the first `bits` bits of the passed value argument.
+    ///
+    /// z-base-32 is the only base that can encode a bit count that is not a whole number of bytes,
+    /// e.g., 10 bits are encoded into 2 chars instead of the 4 chars that 2 whole bytes need;
+    /// every other base encodes the first `bits.div_ceil(8)` bytes, with the bits that come
+    /// after the bit count zeroed out
+    ///
+    /// # Panic
+    /// panics if `bits` is greater than the bit length of value
+    pub fn encode_bits(&self, value: impl AsRef<[u8]>, bits: usize) -> String {
+        let value = value.as_ref();
+        assert!(
+            bits <= value.len() * 8,
+            "can not encode {} bits out of a {} bits value",
+            bits,
+            value.len() * 8
+        );
+
+        if self.base == Base::_32Z {
+            let mut encoded = String::new();
+            base32_z_encode_bits(value, bits, &mut encoded);
+
+            return encoded;
+        }
+        let mut value = value.to_vec();
+        crate::truncate_bits(&mut value, bits);
+
+        self.encode_bytes(&value)
+    }
 }
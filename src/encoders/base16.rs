@@ -1,39 +1,19 @@
 #![cfg(feature = "base16")]
-use crate::makura_alloc::{String, Vec};
+use super::Sink;
 
 use crate::BASE16;
 use crate::char_from_idx;
 
-/// separates the input string into chunks of 24bits
-fn into_octets(data: &str) -> Vec<u8> {
-    data.into()
-}
-
-fn into_4bits_bytes(bytes: Vec<u8>) -> Vec<u8> {
-    let bytes = bytes.into_iter();
-    // let mut last = bytes.next_back().unwrap();
-
-    bytes.map(|b| [(b >> 4) & 15, b & 15]).flatten().collect()
-}
-
-fn into_base16(bytes: Vec<u8>) -> String {
-    bytes
-        .into_iter()
-        .map(|b| char_from_idx(b, &BASE16))
-        .collect::<String>()
-}
-
-pub fn base16_encode<T>(value: T) -> String
-where
-    T: AsRef<str>,
-{
-    let value = value.as_ref();
-    if value.is_empty() {
-        return "".into();
-    }
-
-    let chunks = into_octets(value);
-    let bytes = into_4bits_bytes(chunks);
-
-    into_base16(bytes)
+pub fn base16_encode(value: &[u8], encoded: &mut impl Sink) {
+    #[cfg(feature = "simd")]
+    let value = match encoded.as_string() {
+        Some(encoded) => &value[crate::simd::encode(value, &BASE16, encoded)..],
+        None => value,
+    };
+
+    encoded.reserve(value.len() * 2);
+    value.iter().for_each(|b| {
+        encoded.push(char_from_idx(b >> 4, &BASE16));
+        encoded.push(char_from_idx(b & 15, &BASE16));
+    });
 }
@@ -1,175 +1,135 @@
-#![cfg(any(feature = "base32", feature = "base32_hex"))]
-use crate::makura_alloc::{String, Vec};
+#![cfg(any(
+    feature = "base32",
+    feature = "base32_hex",
+    feature = "base32_nsec3",
+    feature = "base32_z",
+    feature = "bech32"
+))]
+use crate::makura_alloc::Vec;
 
-use crate::char_from_idx;
-use crate::{BASE32, BASE32HEX};
-
-/// DOCS
-/// Special processing is performed if fewer than 40 bits are available
-/// at the end of the data being encoded.  A full encoding quantum is
-/// always completed at the end of a body.  When fewer than 40 input bits
-/// are available in an input group, bits with value zero are added (on
-/// the right) to form an integral number of 5-bit groups.  Padding at
-/// the end of the data is performed using the "=" character.  Since all
-/// base 32 input is an integral number of octets, only the following
-/// cases can arise:
-///
-/// (1) The final quantum of encoding input is an integral multiple of 40
-///     bits; here, the final unit of encoded output will be an integral
-///     multiple of 8 characters with no "=" padding.
-///
-/// (2) The final quantum of encoding input is exactly 8 bits; here, the
-///     final unit of encoded output will be two characters followed by
-///     six "=" padding characters.
-///
-/// (3) The final quantum of encoding input is exactly 16 bits; here, the
-///     final unit of encoded output will be four characters followed by
-///     four "=" padding characters.
-///
-/// (4) The final quantum of encoding input is exactly 24 bits; here, the
-///     final unit of encoded output will be five characters followed by
-///     three "=" padding characters.
-///
-/// (5) The final quantum of encoding input is exactly 32 bits; here, the
-///     final unit of encoded output will be seven characters followed by
-///     one "=" padding character.
-
-/// separates the input string into chunks of 24bits
-fn into_40bits_chunks(data: &str) -> Vec<u64> {
-    let mut bytes = data.as_bytes().chunks(5);
-    // println!("{:?}", bytes.clone().collect::<Vec<&[u8]>>());
-    let last = bytes.next_back().unwrap();
-
-    let mut bytes = bytes
-        .map(|b| {
-            let mut mask = 0u64;
-            mask |= b[0] as u64;
-            mask <<= 8;
-            mask |= b[1] as u64;
-            mask <<= 8;
-            mask |= b[2] as u64;
-            mask <<= 8;
-            mask |= b[3] as u64;
-            mask <<= 8;
-            mask |= b[4] as u64;
-
-            mask
-        })
-        .collect::<Vec<u64>>();
-    let last = {
-        let mut mask = 0u64;
-        mask |= last[0] as u64;
-        mask <<= 8;
-        mask |= if last.len() < 2 { 0u64 } else { last[1] as u64 };
-        mask <<= 8;
-        mask |= if last.len() < 3 { 0u64 } else { last[2] as u64 };
-        mask <<= 8;
-        mask |= if last.len() < 4 { 0u64 } else { last[3] as u64 };
-        mask <<= 8;
-        mask |= if last.len() < 5 { 0u64 } else { last[4] as u64 };
-
-        mask
-    };
-    bytes.push(last);
+use super::Sink;
 
-    bytes
-}
-
-fn into_5bits_bytes(bytes: Vec<u64>) -> Vec<u8> {
-    let bytes = bytes.into_iter();
-    // let mut last = bytes.next_back().unwrap();
-
-    bytes
-        .map(|b| {
-            [
-                (b >> 35) as u8 & 31,
-                (b >> 30) as u8 & 31,
-                (b >> 25) as u8 & 31,
-                (b >> 20) as u8 & 31,
-                (b >> 15) as u8 & 31,
-                (b >> 10) as u8 & 31,
-                (b >> 5) as u8 & 31,
-                b as u8 & 31,
-            ]
-        })
-        .flatten()
-        .collect()
+use crate::char_from_idx;
+use crate::{BASE32, BASE32HEX, BASE32NSEC3, BASE32Z, Base};
+
+// DOCS
+// Special processing is performed if fewer than 40 bits are available
+// at the end of the data being encoded.  A full encoding quantum is
+// always completed at the end of a body.  When fewer than 40 input bits
+// are available in an input group, bits with value zero are added (on
+// the right) to form an integral number of 5-bit groups.  Padding at
+// the end of the data is performed using the "=" character.  Since all
+// base 32 input is an integral number of octets, only the following
+// cases can arise:
+//
+// (1) The final quantum of encoding input is an integral multiple of 40
+//     bits; here, the final unit of encoded output will be an integral
+//     multiple of 8 characters with no "=" padding.
+//
+// (2) The final quantum of encoding input is exactly 8 bits; here, the
+//     final unit of encoded output will be two characters followed by
+//     six "=" padding characters.
+//
+// (3) The final quantum of encoding input is exactly 16 bits; here, the
+//     final unit of encoded output will be four characters followed by
+//     four "=" padding characters.
+//
+// (4) The final quantum of encoding input is exactly 24 bits; here, the
+//     final unit of encoded output will be five characters followed by
+//     three "=" padding characters.
+//
+// (5) The final quantum of encoding input is exactly 32 bits; here, the
+//     final unit of encoded output will be seven characters followed by
+//     one "=" padding character.
+
+/// regroups a chunk of up to 5 bytes into 8 table indices of 5 bits,
+/// the missing bytes of a final partial chunk are zero filled
+pub(crate) fn into_5bits_chunk(chunk: &[u8]) -> [u8; 8] {
+    let b = |i: usize| chunk.get(i).copied().unwrap_or(0);
+    let (b0, b1, b2, b3, b4) = (b(0), b(1), b(2), b(3), b(4));
+
+    [
+        b0 >> 3,
+        (b0 & 7) << 2 | b1 >> 6,
+        (b1 >> 1) & 31,
+        (b1 & 1) << 4 | b2 >> 4,
+        (b2 & 15) << 1 | b3 >> 7,
+        (b3 >> 2) & 31,
+        (b3 & 3) << 3 | b4 >> 5,
+        b4 & 31,
+    ]
 }
 
-fn into_base32(bytes: Vec<u8>) -> String {
-    let bytes = bytes.into_iter();
-
-    let mut cd = 6;
-    let mut pad = true;
-    bytes
-        .rev()
-        .map(|b| {
-            if cd > 0 && pad && b == 0 {
-                cd -= 1;
-                '='
-            } else {
-                pad = false;
-                char_from_idx(b, &BASE32)
-            }
-        })
-        .collect::<Vec<char>>()
-        .into_iter()
-        .rev()
-        .collect()
+// writes the chars of every 5 bytes chunk straight into the output,
+// a final partial chunk only keeps the chars that carry input bits,
+// then gets padded up to 8 chars if the base is padded
+fn encode_5bits(value: &[u8], base: &Base, encoded: &mut impl Sink) {
+    let pad = base.pad_char();
+    encoded.reserve(value.len().div_ceil(5) * 8);
+
+    value.chunks(5).for_each(|chunk| {
+        let values = into_5bits_chunk(chunk);
+        let len = (chunk.len() * 8).div_ceil(5);
+        values[..len]
+            .iter()
+            .for_each(|b| encoded.push(char_from_idx(*b, base)));
+        if let Some(pad) = pad {
+            (len..8).for_each(|_| encoded.push(pad));
+        }
+    });
 }
 
-fn into_base32_hex(bytes: Vec<u8>) -> String {
-    let bytes = bytes.into_iter();
+// regroups the first `bits` bits of the input into 5 bits values without any padding,
+// the values are cut at the last one that still carries input bits
+// the unused bits of the last value are zero filled
+//
+// this is the regrouping that z-base-32 and bech32 share
+#[cfg(any(feature = "base32_z", feature = "bech32"))]
+pub(crate) fn into_5bits_values(data: &[u8], bits: usize) -> Vec<u8> {
+    let len = bits.div_ceil(5);
+    let mut values = Vec::with_capacity(len.next_multiple_of(8));
+    data[..bits.div_ceil(8)]
+        .chunks(5)
+        .for_each(|chunk| values.extend_from_slice(&into_5bits_chunk(chunk)));
+    values.truncate(len);
+
+    // the bits of the last value that come after the bit count are dropped
+    if bits % 5 != 0 {
+        if let Some(last) = values.last_mut() {
+            *last &= 31 << (5 - bits % 5) & 31;
+        }
+    }
 
-    // FIXME the table needs to have all values
-    let mut cd = 6;
-    let mut pad = true;
-    bytes
-        .rev()
-        // .inspect(|b| println!("{}", b))
-        .map(|b| {
-            if cd > 0 && pad && b == 0 {
-                cd -= 1;
-                '='
-            } else {
-                pad = false;
-                char_from_idx(b, &BASE32HEX)
-            }
-        })
-        .collect::<Vec<char>>()
-        .into_iter()
-        .rev()
-        .collect()
+    values
 }
 
 #[cfg(feature = "base32")]
-pub fn base32_encode<T>(value: T) -> String
-where
-    T: AsRef<str>,
-{
-    let value = value.as_ref();
-    if value.is_empty() {
-        return "".into();
-    }
-
-    let chunks = into_40bits_chunks(value);
-    let bytes = into_5bits_bytes(chunks);
-
-    into_base32(bytes)
+pub fn base32_encode(value: &[u8], encoded: &mut impl Sink) {
+    encode_5bits(value, &BASE32, encoded)
 }
 
 #[cfg(feature = "base32_hex")]
-pub fn base32_hex_encode<T>(value: T) -> String
-where
-    T: AsRef<str>,
-{
-    let value = value.as_ref();
-    if value.is_empty() {
-        return "".into();
-    }
+pub fn base32_hex_encode(value: &[u8], encoded: &mut impl Sink) {
+    encode_5bits(value, &BASE32HEX, encoded)
+}
 
-    let chunks = into_40bits_chunks(value);
-    let bytes = into_5bits_bytes(chunks);
+#[cfg(feature = "base32_nsec3")]
+pub fn base32_nsec3_encode(value: &[u8], encoded: &mut impl Sink) {
+    // the nsec3 table is the base 32 hex table,
+    // its lowercase comes from the encoder like it does for the other case insensitive tables
+    encode_5bits(value, &BASE32NSEC3, encoded)
+}
 
-    into_base32_hex(bytes)
+#[cfg(feature = "base32_z")]
+pub fn base32_z_encode(value: &[u8], encoded: &mut impl Sink) {
+    encode_5bits(value, &BASE32Z, encoded)
+}
+
+/// encodes only the first `bits` bits of the value
+/// which makes for a shorter output when the encoded data is not a whole number of bytes
+#[cfg(feature = "base32_z")]
+pub fn base32_z_encode_bits(value: &[u8], bits: usize, encoded: &mut impl Sink) {
+    into_5bits_values(value, bits)
+        .into_iter()
+        .for_each(|b| encoded.push(char_from_idx(b, &BASE32Z)))
 }
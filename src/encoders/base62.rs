@@ -0,0 +1,37 @@
+#![cfg(any(feature = "base36", feature = "base58", feature = "base62"))]
+use super::Sink;
+
+use crate::BigBaseTransformer;
+use crate::char_from_idx;
+use crate::{BASE36, BASE58, BASE62, Base};
+
+// neither 36, 58 nor 62 are powers of 2, so the input bits can't be regrouped into table indices
+// instead, the whole input is treated as one big base 256 integer
+// that gets transformed into a base 36/58/62 integer
+
+/// transforms the input bytes into the digits of the given base
+/// and writes their chars straight into the output
+fn encode_base_digits(data: &[u8], base: &Base, radix: u32, encoded: &mut impl Sink) {
+    let mut transformer = BigBaseTransformer::new(256, radix, data);
+    transformer.transform_all();
+
+    transformer
+        .sequence()
+        .iter()
+        .for_each(|b| encoded.push(char_from_idx(*b, base)))
+}
+
+#[cfg(feature = "base36")]
+pub fn base36_encode(value: &[u8], encoded: &mut impl Sink) {
+    encode_base_digits(value, &BASE36, 36, encoded)
+}
+
+#[cfg(feature = "base58")]
+pub fn base58_encode(value: &[u8], encoded: &mut impl Sink) {
+    encode_base_digits(value, &BASE58, 58, encoded)
+}
+
+#[cfg(feature = "base62")]
+pub fn base62_encode(value: &[u8], encoded: &mut impl Sink) {
+    encode_base_digits(value, &BASE62, 62, encoded)
+}
@@ -1,145 +1,110 @@
-#![cfg(any(feature = "base64", feature = "base64_url"))]
-use crate::makura_alloc::{String, Vec};
-
-use crate::{BASE64, BASE64URL};
-use crate::{PAD, char_from_idx};
-
-/// separates the input string into chunks of 24bits
-fn into_24bits_chunks(data: &str) -> Vec<u32> {
-    let mut bytes = data.as_bytes().chunks(3);
-    // println!("{:?}", bytes.clone().collect::<Vec<&[u8]>>());
-    let last = bytes.next_back().unwrap();
-
-    let mut bytes = bytes
-        .map(|b| {
-            let mut mask = 0u32;
-            mask |= b[0] as u32;
-            mask <<= 8;
-            mask |= b[1] as u32;
-            mask <<= 8;
-            mask |= b[2] as u32;
-
-            mask
-        })
-        .collect::<Vec<u32>>();
-    let last = {
-        let mut mask = 0u32;
-        mask |= last[0] as u32;
-        mask <<= 8;
-        mask |= if last.len() < 2 { 0u32 } else { last[1] as u32 };
-        mask <<= 8;
-        mask |= if last.len() < 3 { 0u32 } else { last[2] as u32 };
-
-        mask
-    };
-    bytes.push(last);
-
-    bytes
+#![cfg(any(
+    feature = "base64",
+    feature = "base64_url",
+    feature = "base64_bcrypt",
+    feature = "base64_crypt",
+    feature = "base64_imap",
+    feature = "base64_xml_name",
+    feature = "base64_xml_nmtoken",
+    feature = "base64_y64",
+    feature = "uuencode"
+))]
+use super::Sink;
+use crate::char_from_idx;
+use crate::{BASE64, BASE64URL, Base};
+
+// DOCS
+// (1) The final quantum of encoding input is an integral multiple of 24
+//     bits; here, the final unit of encoded output will be an integral
+//     multiple of 4 characters with no "=" padding.
+//
+// (2) The final quantum of encoding input is exactly 8 bits; here, the
+//     final unit of encoded output will be two characters followed by
+//     two "=" padding characters.
+//
+// (3) The final quantum of encoding input is exactly 16 bits; here, the
+//     final unit of encoded output will be three characters followed by
+//     one "=" padding character.
+
+/// regroups a chunk of up to 3 bytes into 4 table indices of 6 bits,
+/// the missing bytes of a final partial chunk are zero filled
+pub(crate) fn into_6bits_values(chunk: &[u8]) -> [u8; 4] {
+    let b = |i: usize| chunk.get(i).copied().unwrap_or(0);
+    let (b0, b1, b2) = (b(0), b(1), b(2));
+
+    [
+        b0 >> 2,
+        (b0 & 3) << 4 | b1 >> 4,
+        (b1 & 15) << 2 | b2 >> 6,
+        b2 & 63,
+    ]
 }
 
-fn into_6bits_bytes(bytes: Vec<u32>) -> Vec<u8> {
-    let bytes = bytes.into_iter();
-    // let mut last = bytes.next_back().unwrap();
+/// same as into_6bits_values but in crypt(3)'s order,
+/// the chunk is read as a little endian 24 bits number whose lowest 6 bits come first
+#[cfg(feature = "base64_crypt")]
+fn into_6bits_values_crypt(chunk: &[u8]) -> [u8; 4] {
+    let w = chunk.iter().rev().fold(0u32, |w, b| w << 8 | *b as u32);
 
-    bytes
-        .map(|b| {
-            [
-                (b >> 18) as u8 & 63,
-                (b >> 12) as u8 & 63,
-                (b >> 6) as u8 & 63,
-                b as u8 & 63,
-            ]
-        })
-        .flatten()
-        .collect()
+    [0, 6, 12, 18].map(|shift| (w >> shift) as u8 & 63)
 }
 
-fn into_base64(bytes: Vec<u8>) -> String {
-    let mut bytes = bytes.into_iter();
-    let [last, before_last] = [bytes.next_back(), bytes.next_back()];
-
-    // FIXME the table needs to have all values
-    let mut encoded = bytes.map(|b| char_from_idx(b, &BASE64)).collect::<String>();
-
-    match [before_last, last] {
-        [Some(0), Some(0)] => encoded.extend([PAD, PAD]),
-        [Some(b0), Some(0)] => encoded.extend([char_from_idx(b0, &BASE64), PAD]),
-        [Some(b0), None] => encoded.push(char_from_idx(b0, &BASE64)),
-        [Some(0), Some(b1)] => {
-            encoded.extend([char_from_idx(0, &BASE64), char_from_idx(b1, &BASE64)])
+// writes the chars of every 3 bytes chunk straight into the output,
+// a final partial chunk of n bytes only keeps its first n + 1 chars,
+// then gets padded up to 4 chars if the base has a pad char
+fn encode_6bits(value: &[u8], base: &Base, encoded: &mut impl Sink, regroup: fn(&[u8]) -> [u8; 4]) {
+    let pad = base.pad_char();
+    encoded.reserve(value.len().div_ceil(3) * 4);
+
+    value.chunks(3).for_each(|chunk| {
+        let values = regroup(chunk);
+        let len = chunk.len() + 1;
+        values[..len]
+            .iter()
+            .for_each(|b| encoded.push(char_from_idx(*b, base)));
+        if let Some(pad) = pad {
+            (len..4).for_each(|_| encoded.push(pad));
         }
-        [Some(b0), Some(b1)] => {
-            encoded.extend([char_from_idx(b0, &BASE64), char_from_idx(b1, &BASE64)])
-        }
-        [None, None] => unreachable!("empty vector quit is much earlier"),
-        [None, Some(_)] => unreachable!("cant find more data after the end"),
-    }
-
-    // if encoded.ends_with("AA") {
-    // } else if encoded.ends_with('A') {
-    // }
-
-    encoded
+    });
 }
 
-fn into_base64_url(bytes: Vec<u8>) -> String {
-    let mut bytes = bytes.into_iter();
-    let [last, before_last] = [bytes.next_back(), bytes.next_back()];
-
-    // FIXME the table needs to have all values
-    let mut encoded = bytes
-        .map(|b| char_from_idx(b, &BASE64URL))
-        .collect::<String>();
-
-    match [before_last, last] {
-        [Some(0), Some(0)] => encoded.extend([PAD, PAD]),
-        [Some(b0), Some(0)] => encoded.extend([char_from_idx(b0, &BASE64URL), PAD]),
-        [Some(b0), None] => encoded.push(char_from_idx(b0, &BASE64URL)),
-        [Some(0), Some(b1)] => {
-            encoded.extend([char_from_idx(0, &BASE64URL), char_from_idx(b1, &BASE64URL)])
-        }
-        [Some(b0), Some(b1)] => {
-            encoded.extend([char_from_idx(b0, &BASE64URL), char_from_idx(b1, &BASE64URL)])
-        }
-        [None, None] => unreachable!("empty vector quit is much earlier"),
-        [None, Some(_)] => unreachable!("cant find more data after the end"),
-    }
-
-    // if encoded.ends_with("AA") {
-    // } else if encoded.ends_with('A') {
-    // }
+#[cfg(feature = "base64")]
+pub fn base64_encode(value: &[u8], encoded: &mut impl Sink) {
+    #[cfg(feature = "simd")]
+    let value = match encoded.as_string() {
+        Some(encoded) => &value[crate::simd::encode(value, &BASE64, encoded)..],
+        None => value,
+    };
 
-    encoded
+    encode_6bits(value, &BASE64, encoded, into_6bits_values)
 }
 
-#[cfg(feature = "base64")]
-pub fn base64_encode<T>(value: T) -> String
-where
-    T: AsRef<str>,
-{
-    let value = value.as_ref();
-    if value.is_empty() {
-        return "".into();
-    }
-
-    let chunks = into_24bits_chunks(value);
-    let bytes = into_6bits_bytes(chunks);
+#[cfg(feature = "base64_url")]
+pub fn base64_url_encode(value: &[u8], encoded: &mut impl Sink) {
+    #[cfg(feature = "simd")]
+    let value = match encoded.as_string() {
+        Some(encoded) => &value[crate::simd::encode(value, &BASE64URL, encoded)..],
+        None => value,
+    };
 
-    into_base64(bytes)
+    encode_6bits(value, &BASE64URL, encoded, into_6bits_values)
 }
 
-#[cfg(feature = "base64_url")]
-pub fn base64_url_encode<T>(value: T) -> String
-where
-    T: AsRef<str>,
-{
-    let value = value.as_ref();
-    if value.is_empty() {
-        return "".into();
+// the base 64 profiles share the base 64 regrouping and only differ in their tables
+// and their padding, except for crypt(3) which regroups the bytes in its own order
+#[cfg(any(
+    feature = "base64_bcrypt",
+    feature = "base64_crypt",
+    feature = "base64_imap",
+    feature = "base64_xml_name",
+    feature = "base64_xml_nmtoken",
+    feature = "base64_y64"
+))]
+pub fn base64_profile_encode(value: &[u8], base: &Base, encoded: &mut impl Sink) {
+    #[cfg(feature = "base64_crypt")]
+    if base.is_64_crypt() {
+        return encode_6bits(value, base, encoded, into_6bits_values_crypt);
     }
 
-    let chunks = into_24bits_chunks(value);
-    let bytes = into_6bits_bytes(chunks);
-
-    into_base64_url(bytes)
+    encode_6bits(value, base, encoded, into_6bits_values)
 }
@@ -0,0 +1,66 @@
+#![cfg(any(feature = "base85", feature = "base85_ascii", feature = "base85_z"))]
+use super::Sink;
+
+use crate::Base;
+use crate::char_from_idx;
+
+// all base 85 variants pack 4 bytes into 5 base 85 digits
+// they only differ in their encoding tables and framing
+//
+// a final partial group of n bytes is zero padded to 4 bytes,
+// then only the first n + 1 digits of its encoding are kept
+
+/// turns a chunk of up to 4 bytes into 5 base 85 digits, most significant digit first,
+/// the missing bytes of a final partial chunk are zero filled
+fn into_85digits(chunk: &[u8]) -> [u8; 5] {
+    let mut n = (0..4).fold(0u32, |n, i| {
+        n << 8 | chunk.get(i).copied().unwrap_or(0) as u32
+    });
+    let mut digits = [0u8; 5];
+    for digit in digits.iter_mut().rev() {
+        *digit = (n % 85) as u8;
+        n /= 85;
+    }
+
+    digits
+}
+
+// writes the digits of every 4 bytes chunk straight into the output
+fn encode_85digits(value: &[u8], base: &Base, encoded: &mut impl Sink) {
+    value.chunks(4).for_each(|chunk| {
+        into_85digits(chunk)[..chunk.len() + 1]
+            .iter()
+            .for_each(|b| encoded.push(char_from_idx(*b, base)))
+    });
+}
+
+#[cfg(feature = "base85")]
+pub fn base85_encode(value: &[u8], encoded: &mut impl Sink) {
+    encoded.reserve(value.len().div_ceil(4) * 5);
+    encode_85digits(value, &crate::BASE85, encoded)
+}
+
+#[cfg(feature = "base85_ascii")]
+pub fn base85_ascii_encode(value: &[u8], encoded: &mut impl Sink) {
+    // ascii85 wraps the encoded data in '<~' '~>' delimiters
+    // and compresses whole chunks of zeros into a single 'z' char
+    encoded.reserve(value.len().div_ceil(4) * 5 + 4);
+    encoded.push_str("<~");
+    value.chunks(4).for_each(|chunk| match chunk {
+        [0, 0, 0, 0] => encoded.push('z'),
+        chunk => encode_85digits(chunk, &crate::BASE85ASCII, encoded),
+    });
+    encoded.push_str("~>");
+}
+
+// z85 has no partial groups, the value has to be made of whole chunks of 4 bytes
+#[cfg(feature = "base85_z")]
+pub fn base85_z_encode(value: &[u8], encoded: &mut impl Sink) {
+    assert!(
+        value.len() % 4 == 0,
+        "z85 can not encode a {} bytes value, its length has to be a multiple of 4",
+        value.len()
+    );
+    encoded.reserve(value.len().div_ceil(4) * 5);
+    encode_85digits(value, &crate::BASE85Z, encoded)
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "base91")]
+use super::Sink;
+
+use crate::BASE91;
+use crate::char_from_idx;
+
+// base91 doesn't work on fixed size chunks
+// bits are queued up until there are more than 13 of them,
+// then 13 bits are taken if their value is greater than 88, otherwise 14 bits are taken
+// either way, the taken value is written out as 2 base 91 digits
+//
+// 91 * 91 = 8281 > 2^13 = 8192, so the values 0 -> 88 can borrow a 14th bit
+// and still fit into 2 digits
+
+/// writes the base 91 chars of the input bytes straight into the output
+pub fn base91_encode(value: &[u8], encoded: &mut impl Sink) {
+    encoded.reserve(value.len() * 16 / 13 + 2);
+    let mut push = |value: u32| encoded.push(char_from_idx(value as u8, &BASE91));
+    let mut queue = 0u32;
+    let mut bits = 0u32;
+
+    value.iter().for_each(|b| {
+        queue |= (*b as u32) << bits;
+        bits += 8;
+        if bits > 13 {
+            let mut value = queue & 8191;
+            if value > 88 {
+                queue >>= 13;
+                bits -= 13;
+            } else {
+                value = queue & 16383;
+                queue >>= 14;
+                bits -= 14;
+            }
+            push(value % 91);
+            push(value / 91);
+        }
+    });
+
+    // flush the bits that are left in the queue
+    if bits > 0 {
+        push(queue % 91);
+        if bits > 7 || queue > 90 {
+            push(queue / 91);
+        }
+    }
+}
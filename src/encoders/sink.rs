@@ -0,0 +1,83 @@
+use core::fmt;
+
+use crate::makura_alloc::String;
+
+/// the output that the per base encoders write their chars into
+///
+/// a String never fails to take a char, so pushing is infallible,
+/// the sinks that can fail keep their first error around instead
+pub(crate) trait Sink {
+    fn push(&mut self, ch: char);
+
+    fn push_str(&mut self, value: &str) {
+        value.chars().for_each(|ch| self.push(ch));
+    }
+
+    fn reserve(&mut self, _additional: usize) {}
+
+    // the simd kernels write whole blocks of bytes at a time, only a String can take them
+    #[cfg(feature = "simd")]
+    fn as_string(&mut self) -> Option<&mut String> {
+        None
+    }
+}
+
+impl Sink for String {
+    fn push(&mut self, ch: char) {
+        String::push(self, ch)
+    }
+
+    fn push_str(&mut self, value: &str) {
+        String::push_str(self, value)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        String::reserve(self, additional)
+    }
+
+    #[cfg(feature = "simd")]
+    fn as_string(&mut self) -> Option<&mut String> {
+        Some(self)
+    }
+}
+
+/// writes the chars into a `fmt::Write`, e.g., a Formatter
+///
+/// once a write fails, the remaining chars are dropped and the error is kept in `.res`
+pub(crate) struct FmtSink<'a, W: fmt::Write + ?Sized> {
+    out: &'a mut W,
+    pub(crate) res: fmt::Result,
+}
+
+impl<'a, W: fmt::Write + ?Sized> FmtSink<'a, W> {
+    pub(crate) fn new(out: &'a mut W) -> Self {
+        Self { out, res: Ok(()) }
+    }
+}
+
+impl<W: fmt::Write + ?Sized> Sink for FmtSink<'_, W> {
+    fn push(&mut self, ch: char) {
+        if self.res.is_ok() {
+            self.res = self.out.write_char(ch);
+        }
+    }
+
+    fn push_str(&mut self, value: &str) {
+        if self.res.is_ok() {
+            self.res = self.out.write_str(value);
+        }
+    }
+}
+
+/// lowercases the chars on their way into the inner sink
+pub(crate) struct Lowercase<'a, S: Sink + ?Sized>(pub(crate) &'a mut S);
+
+impl<S: Sink + ?Sized> Sink for Lowercase<'_, S> {
+    fn push(&mut self, ch: char) {
+        self.0.push(ch.to_ascii_lowercase())
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.0.reserve(additional)
+    }
+}
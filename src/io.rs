@@ -0,0 +1,396 @@
+#![cfg(any(feature = "tokio", feature = "futures-io"))]
+//! async adapters that encode the bytes written into a writer
+//! and decode the bytes read from a reader, without blocking the executor
+//!
+//! `EncodeWriter` and `DecodeReader` implement the `AsyncWrite` and `AsyncRead` traits of tokio
+//! with the `tokio` feature, and the ones of the futures crates with the `futures-io` feature
+//!
+//! the [block bases](crate::Base::is_block) are worked on a few blocks at a time;
+//! the other bases need the whole value, so they are held until the end of the stream
+//!
+//! ```
+//! # #[cfg(feature = "tokio")]
+//! # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+//! use makura::io::{DecodeReader, EncodeWriter};
+//! use makura::{BASE64, Encoder};
+//! use tokio::io::{AsyncReadExt, AsyncWriteExt};
+//!
+//! let mut writer = EncodeWriter::new(Vec::new(), Encoder::base64());
+//! writer.write_all(b"foob").await.unwrap();
+//! writer.shutdown().await.unwrap();
+//! assert_eq!(writer.get_ref(), b"Zm9vYg==");
+//!
+//! let mut reader = DecodeReader::new(&b"Zm9vYg=="[..], BASE64);
+//! let mut decoded = Vec::new();
+//! reader.read_to_end(&mut decoded).await.unwrap();
+//! assert_eq!(decoded, b"foob");
+//! # });
+//! ```
+//!
+//! the inner readers and writers have to be `Unpin`, a `!Unpin` one can be passed in a `Box::pin`
+use core::pin::Pin;
+use core::task::{Context, Poll, ready};
+use std::io;
+
+use crate::makura_alloc::Vec;
+#[cfg(feature = "decoding")]
+use crate::{Base, DecodeError, Decoder};
+#[cfg(feature = "encoding")]
+use crate::{Encoder, makura_alloc::String};
+
+#[cfg(all(feature = "tokio", feature = "encoding"))]
+pub mod codec;
+
+/// the count of blocks that an adapter works on at a time
+const BLOCKS: usize = 64;
+
+/// a writer that encodes the bytes written into it and writes the encoded chars into
+/// the inner writer
+///
+/// a partial block is held until more bytes come in or the writer is shut down
+/// (closed with the futures traits), which is when the padding is written;
+/// flushing doesn't write the held bytes
+///
+/// shutting down a z85 writer whose bytes count is not a multiple of 4 fails
+/// with an `io::ErrorKind::InvalidInput` error that holds the `DecodeError`
+#[cfg(feature = "encoding")]
+pub struct EncodeWriter<W> {
+    inner: W,
+    encoder: Encoder,
+    // the bytes that don't make a whole block yet
+    bytes: Vec<u8>,
+    encoded: String,
+    pos: usize,
+    finished: bool,
+    // the count of bytes that were written into the writer
+    read: usize,
+}
+
+#[cfg(feature = "encoding")]
+impl<W> EncodeWriter<W> {
+    pub fn new(inner: W, encoder: Encoder) -> Self {
+        Self {
+            inner,
+            encoder,
+            bytes: Vec::new(),
+            encoded: String::new(),
+            pos: 0,
+            finished: false,
+            read: 0,
+        }
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// returns the inner writer, the chars that were not written into it yet are lost
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    // takes as much of buf as the next chunk holds and encodes its whole blocks
+    fn accept(&mut self, buf: &[u8]) -> usize {
+        self.encoded.clear();
+        self.pos = 0;
+        match self.encoder.base().block_len() {
+            Some((block, _)) => {
+                let len = buf.len().min(block * BLOCKS);
+                self.read += len;
+                self.bytes.extend_from_slice(&buf[..len]);
+                let whole = self.bytes.len() / block * block;
+                self.encoder
+                    .encode_into(&self.bytes[..whole], &mut self.encoded);
+                self.bytes.drain(..whole);
+
+                len
+            }
+            None => {
+                self.bytes.extend_from_slice(buf);
+                self.read += buf.len();
+
+                buf.len()
+            }
+        }
+    }
+
+    // encodes the held bytes along with the padding, only once
+    //
+    // fails with an `io::ErrorKind::InvalidInput` error if the written bytes can't be
+    // encoded whole, i.e., z85 bytes whose count is not a multiple of 4
+    fn finish(&mut self) -> io::Result<()> {
+        if self.finished {
+            return Ok(());
+        }
+
+        self.encoder
+            .check_len(self.read)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+        self.finished = true;
+        self.encoder.encode_into(&self.bytes, &mut self.encoded);
+        self.bytes.clear();
+
+        Ok(())
+    }
+
+    // writes the encoded chars into the inner writer until none are left
+    fn poll_drain(
+        &mut self,
+        cx: &mut Context<'_>,
+        mut write: impl FnMut(&mut W, &mut Context<'_>, &[u8]) -> Poll<io::Result<usize>>,
+    ) -> Poll<io::Result<()>> {
+        while self.pos < self.encoded.len() {
+            match ready!(write(
+                &mut self.inner,
+                cx,
+                &self.encoded.as_bytes()[self.pos..]
+            ))? {
+                0 => return Poll::Ready(Err(io::ErrorKind::WriteZero.into())),
+                written => self.pos += written,
+            }
+        }
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(all(feature = "encoding", feature = "tokio"))]
+impl<W: tokio::io::AsyncWrite + Unpin> tokio::io::AsyncWrite for EncodeWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx, |w, cx, buf| Pin::new(w).poll_write(cx, buf)))?;
+
+        Poll::Ready(Ok(this.accept(buf)))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx, |w, cx, buf| Pin::new(w).poll_write(cx, buf)))?;
+
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx, |w, cx, buf| Pin::new(w).poll_write(cx, buf)))?;
+        this.finish()?;
+        ready!(this.poll_drain(cx, |w, cx, buf| Pin::new(w).poll_write(cx, buf)))?;
+
+        Pin::new(&mut this.inner).poll_shutdown(cx)
+    }
+}
+
+#[cfg(all(feature = "encoding", feature = "futures-io"))]
+impl<W: futures_io::AsyncWrite + Unpin> futures_io::AsyncWrite for EncodeWriter<W> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx, |w, cx, buf| Pin::new(w).poll_write(cx, buf)))?;
+
+        Poll::Ready(Ok(this.accept(buf)))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx, |w, cx, buf| Pin::new(w).poll_write(cx, buf)))?;
+
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_drain(cx, |w, cx, buf| Pin::new(w).poll_write(cx, buf)))?;
+        this.finish()?;
+        ready!(this.poll_drain(cx, |w, cx, buf| Pin::new(w).poll_write(cx, buf)))?;
+
+        Pin::new(&mut this.inner).poll_close(cx)
+    }
+}
+
+/// a reader that decodes the chars read from the inner reader
+///
+/// a badly encoded value fails the read with an `io::ErrorKind::InvalidData` error
+/// that holds the `DecodeError`, the reader ends after that
+#[cfg(feature = "decoding")]
+pub struct DecodeReader<R> {
+    inner: R,
+    base: Base,
+    // the chars that don't make a whole block yet
+    chars: Vec<u8>,
+    decoded: Decoded,
+    pos: usize,
+    // the count of chars that were decoded so far
+    read: usize,
+    eof: bool,
+    done: bool,
+}
+
+#[cfg(feature = "decoding")]
+impl<R> DecodeReader<R> {
+    pub fn new(inner: R, base: Base) -> Self {
+        Self {
+            inner,
+            base,
+            chars: Vec::new(),
+            decoded: Decoded(Vec::new()),
+            pos: 0,
+            read: 0,
+            eof: false,
+            done: false,
+        }
+    }
+
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// returns the inner reader, the chars that were read from it but not decoded yet are lost
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    // the length of the chars that can be decoded without seeing the rest of the value
+    fn decodable(&self) -> Result<usize, DecodeError> {
+        if self.eof {
+            return Ok(self.chars.len());
+        }
+
+        let Some((_, block)) = self.base.block_len() else {
+            return Ok(0);
+        };
+        let whole = self.chars.len() / block * block;
+        let pad = self
+            .base
+            .pad_char()
+            .and_then(|pad| self.chars[..whole].iter().position(|&ch| ch as char == pad));
+
+        match pad {
+            // a padded block has to be the last one, see Base::is_block
+            Some(idx) if self.chars.len() > (idx / block + 1) * block => {
+                Err(DecodeError::BadEncodedString)
+            }
+            // the padded block is held until the end of the value
+            Some(idx) => Ok(idx / block * block),
+            None => Ok(whole),
+        }
+    }
+
+    fn decode(&mut self) -> Result<(), DecodeError> {
+        let len = self.decodable()?;
+        let chunk =
+            core::str::from_utf8(&self.chars[..len]).map_err(|_| DecodeError::BadEncodedString)?;
+        Decoder::decode_chunk(chunk, &self.base, self.read, &mut self.decoded.0)?;
+        self.chars.drain(..len);
+        self.read += len;
+
+        Ok(())
+    }
+
+    // reads from the inner reader until there are decoded bytes to hand out or the value ends
+    fn poll_fill(
+        &mut self,
+        cx: &mut Context<'_>,
+        mut read: impl FnMut(&mut R, &mut Context<'_>, &mut [u8]) -> Poll<io::Result<usize>>,
+    ) -> Poll<io::Result<()>> {
+        while self.pos == self.decoded.0.len() && !self.done {
+            crate::wipe(&mut self.decoded.0);
+            self.decoded.0.clear();
+            self.pos = 0;
+            if self.eof {
+                self.done = true;
+                return Poll::Ready(Ok(()));
+            }
+
+            let start = self.chars.len();
+            self.chars.resize(start + BLOCKS * 8, 0);
+            let res = read(&mut self.inner, cx, &mut self.chars[start..]);
+            let len = match &res {
+                Poll::Ready(Ok(len)) => *len,
+                _ => 0,
+            };
+            self.chars.truncate(start + len);
+            ready!(res)?;
+
+            self.eof = len == 0;
+            if let Err(err) = self.decode() {
+                crate::wipe(&mut self.decoded.0);
+                self.decoded.0.clear();
+                self.done = true;
+                return Poll::Ready(Err(io::Error::new(io::ErrorKind::InvalidData, err)));
+            }
+        }
+
+        Poll::Ready(Ok(()))
+    }
+
+    // copies as many decoded bytes into buf as it holds
+    fn take(&mut self, buf: &mut [u8]) -> usize {
+        let len = buf.len().min(self.decoded.0.len() - self.pos);
+        buf[..len].copy_from_slice(&self.decoded.0[self.pos..self.pos + len]);
+        self.pos += len;
+
+        len
+    }
+}
+
+// the decoded bytes that were not read yet, wiped once dropped
+#[cfg(feature = "decoding")]
+struct Decoded(Vec<u8>);
+
+#[cfg(feature = "decoding")]
+impl Drop for Decoded {
+    fn drop(&mut self) {
+        crate::wipe(&mut self.0);
+    }
+}
+
+#[cfg(all(feature = "decoding", feature = "tokio"))]
+impl<R: tokio::io::AsyncRead + Unpin> tokio::io::AsyncRead for DecodeReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut tokio::io::ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_fill(cx, |r, cx, buf| {
+            let mut buf = tokio::io::ReadBuf::new(buf);
+            Pin::new(r)
+                .poll_read(cx, &mut buf)
+                .map_ok(|()| buf.filled().len())
+        }))?;
+
+        let len = this.take(buf.initialize_unfilled());
+        buf.advance(len);
+
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(all(feature = "decoding", feature = "futures-io"))]
+impl<R: futures_io::AsyncRead + Unpin> futures_io::AsyncRead for DecodeReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        ready!(this.poll_fill(cx, |r, cx, buf| Pin::new(r).poll_read(cx, buf)))?;
+
+        Poll::Ready(Ok(this.take(buf)))
+    }
+}
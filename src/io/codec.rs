@@ -0,0 +1,147 @@
+//! a `tokio_util::codec` encoder and decoder for newline delimited frames,
+//! every frame is the encoding of a single message on a line of its own
+//!
+//! ```
+//! use bytes::BytesMut;
+//! use makura::io::codec::FramesCodec;
+//! use tokio_util::codec::{Decoder, Encoder};
+//!
+//! let mut codec = FramesCodec::default();
+//! let mut frames = BytesMut::new();
+//! codec.encode(b"foob", &mut frames).unwrap();
+//! codec.encode(b"bar", &mut frames).unwrap();
+//! assert_eq!(&frames[..], b"Zm9vYg==\nYmFy\n");
+//!
+//! assert_eq!(codec.decode(&mut frames).unwrap().unwrap(), b"foob");
+//! assert_eq!(codec.decode(&mut frames).unwrap().unwrap(), b"bar");
+//! assert!(codec.decode(&mut frames).unwrap().is_none());
+//! ```
+use std::io;
+
+use bytes::{BufMut, BytesMut};
+
+use crate::makura_alloc::Vec;
+
+/// encodes every message into a line of its own and decodes every line back into a message,
+/// a line may end with "\r\n" as well
+///
+/// `FramesCodec::default()` works with padded base 64 frames
+pub struct FramesCodec {
+    encoder: crate::Encoder,
+    max_len: usize,
+    // the index of the buffer that the search for the next newline starts at
+    next: usize,
+}
+
+impl FramesCodec {
+    pub fn new(encoder: crate::Encoder) -> Self {
+        Self {
+            encoder,
+            max_len: usize::MAX,
+            next: 0,
+        }
+    }
+
+    /// limits the length of the encoded frames that the decoder accepts,
+    /// a longer frame fails the decoding with an `io::ErrorKind::InvalidData` error
+    /// instead of being buffered
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+
+        self
+    }
+
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    #[cfg(feature = "decoding")]
+    fn decode_frame(&self, frame: &[u8]) -> Result<Vec<u8>, io::Error> {
+        let frame = frame.strip_suffix(b"\n").unwrap_or(frame);
+        let frame = frame.strip_suffix(b"\r").unwrap_or(frame);
+        if frame.len() > self.max_len {
+            return Err(invalid_data("frame is longer than max_len"));
+        }
+
+        let frame = core::str::from_utf8(frame)
+            .map_err(|_| invalid_data(crate::DecodeError::BadEncodedString))?;
+        let mut decoded = Vec::new();
+        crate::Decoder::decode_into(frame, *self.encoder.base(), &mut decoded)
+            .map_err(invalid_data)?;
+
+        Ok(decoded)
+    }
+}
+
+#[cfg(feature = "base64")]
+impl Default for FramesCodec {
+    fn default() -> Self {
+        Self::new(crate::Encoder::base64())
+    }
+}
+
+#[cfg(feature = "decoding")]
+fn invalid_data(
+    err: impl Into<std::boxed::Box<dyn core::error::Error + Send + Sync>>,
+) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+#[cfg(feature = "encoding")]
+impl<T: AsRef<[u8]>> tokio_util::codec::Encoder<T> for FramesCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> Result<(), io::Error> {
+        // z85 can only encode whole groups
+        self.encoder
+            .check_len(item.as_ref().len())
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+        self.encoder
+            .encode_to_fmt(item, dst)
+            .map_err(|_| io::Error::other("formatting the frame failed"))?;
+        dst.put_u8(b'\n');
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "decoding")]
+impl tokio_util::codec::Decoder for FramesCodec {
+    type Item = Vec<u8>;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Vec<u8>>, io::Error> {
+        match src[self.next..].iter().position(|&b| b == b'\n') {
+            Some(idx) => {
+                let frame = src.split_to(self.next + idx + 1);
+                self.next = 0;
+
+                self.decode_frame(&frame).map(Some)
+            }
+            // the "\r\n" of a frame that is exactly max_len chars long may not be in yet
+            None if src.len() > self.max_len.saturating_add(1) => {
+                Err(invalid_data("frame is longer than max_len"))
+            }
+            None => {
+                self.next = src.len();
+
+                Ok(None)
+            }
+        }
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Vec<u8>>, io::Error> {
+        if let Some(decoded) = self.decode(src)? {
+            return Ok(Some(decoded));
+        }
+        if src.is_empty() {
+            return Ok(None);
+        }
+
+        // the last frame doesn't have to end with a newline
+        let frame = src.split();
+        self.next = 0;
+
+        self.decode_frame(&frame).map(Some)
+    }
+}
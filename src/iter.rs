@@ -0,0 +1,210 @@
+//! lazy encoding and decoding of byte and char iterators
+//!
+//! the [block bases](crate::Base::is_block) are read a few blocks at a time,
+//! so the whole value is never held in memory;
+//! the other bases need the whole value before they can produce their first symbol,
+//! so they read their whole input on the first call to next
+//!
+//! ```
+//! use makura::{BASE64, Decoder, Encoder};
+//!
+//! let encoded = Encoder::base64().encode_iter(b"foob".iter().copied());
+//! assert_eq!(encoded.collect::<String>(), "Zm9vYg==");
+//!
+//! let decoded = Decoder::decode_iter("Zm9vYg==".chars(), BASE64);
+//! assert_eq!(decoded.collect::<Result<Vec<u8>, _>>().unwrap(), b"foob");
+//! ```
+use crate::makura_alloc::{String, Vec};
+
+#[cfg(feature = "encoding")]
+use crate::Encoder;
+#[cfg(feature = "decoding")]
+use crate::{Base, DecodeError, Decoder};
+
+/// the count of blocks that an iterator reads from its input at a time
+const BLOCKS: usize = 64;
+
+/// an iterator over the encoded chars of a byte iterator,
+/// returned by `Encoder::encode_iter`
+#[cfg(feature = "encoding")]
+pub struct EncodeIter<I> {
+    encoder: Encoder,
+    input: I,
+    bytes: Vec<u8>,
+    encoded: String,
+    pos: usize,
+    done: bool,
+    // the count of bytes that were read from the input
+    read: usize,
+    // the length of an input that the encoder can't encode whole
+    bad_len: Option<usize>,
+}
+
+#[cfg(feature = "encoding")]
+impl<I: Iterator<Item = u8>> EncodeIter<I> {
+    pub(crate) fn new(encoder: Encoder, input: I) -> Self {
+        Self {
+            encoder,
+            input,
+            bytes: Vec::new(),
+            encoded: String::new(),
+            pos: 0,
+            done: false,
+            read: 0,
+            bad_len: None,
+        }
+    }
+
+    /// returns an Err once the iterator ended early
+    /// because the input's length can't be encoded whole,
+    /// i.e., a z85 input whose length is not a multiple of 4;
+    /// the chars of the whole groups that come before the partial one were all produced
+    pub fn error(&self) -> Result<(), crate::DecodeError> {
+        match self.bad_len {
+            Some(len) => Err(crate::DecodeError::BadLenForBase(len)),
+            None => Ok(()),
+        }
+    }
+
+    // encodes the next chunk of the input, returns false once the input is exhausted
+    fn refill(&mut self) -> bool {
+        if self.done {
+            return false;
+        }
+
+        self.bytes.clear();
+        self.encoded.clear();
+        self.pos = 0;
+        match self.encoder.base().block_len() {
+            Some((block, _)) => {
+                self.bytes.extend(self.input.by_ref().take(block * BLOCKS));
+                // only a partial chunk can hold a partial block, i.e., the padding
+                self.done = self.bytes.len() < block * BLOCKS;
+                if self.bytes.is_empty() {
+                    return false;
+                }
+            }
+            None => {
+                self.bytes.extend(self.input.by_ref());
+                self.done = true;
+            }
+        }
+        self.read += self.bytes.len();
+        if self.done && self.encoder.check_len(self.read).is_err() {
+            // only z85 can fail, its partial group is left out
+            self.bad_len = Some(self.read);
+            self.bytes.truncate(self.bytes.len() / 4 * 4);
+        }
+        self.encoder.encode_into(&self.bytes, &mut self.encoded);
+
+        !self.encoded.is_empty()
+    }
+}
+
+#[cfg(feature = "encoding")]
+impl<I: Iterator<Item = u8>> Iterator for EncodeIter<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.pos == self.encoded.len() && !self.refill() {
+            return None;
+        }
+
+        // the encoding tables are all ascii
+        let ch = self.encoded.as_bytes()[self.pos] as char;
+        self.pos += 1;
+
+        Some(ch)
+    }
+}
+
+/// an iterator over the decoded bytes of a char iterator,
+/// returned by `Decoder::decode_iter`
+///
+/// the iterator yields a single Err when the input turns out to be badly encoded,
+/// then it ends
+#[cfg(feature = "decoding")]
+pub struct DecodeIter<I: Iterator<Item = char>> {
+    base: Base,
+    input: core::iter::Peekable<I>,
+    chunk: String,
+    decoded: Vec<u8>,
+    pos: usize,
+    // the count of chars that the previous chunks hold
+    read: usize,
+    done: bool,
+}
+
+#[cfg(feature = "decoding")]
+impl<I: Iterator<Item = char>> DecodeIter<I> {
+    pub(crate) fn new(input: I, base: Base) -> Self {
+        Self {
+            base,
+            input: input.peekable(),
+            chunk: String::new(),
+            decoded: Vec::new(),
+            pos: 0,
+            read: 0,
+            done: false,
+        }
+    }
+
+    // decodes the next chunk of the input, returns None once the input is exhausted
+    fn refill(&mut self) -> Option<Result<(), DecodeError>> {
+        if self.done {
+            return None;
+        }
+
+        self.read += self.chunk.chars().count();
+        self.chunk.clear();
+        self.pos = 0;
+        match self.base.block_len() {
+            Some((_, block)) => {
+                self.chunk.extend(self.input.by_ref().take(block * BLOCKS));
+                self.done = self.input.peek().is_none();
+                // a padded chunk has to be the last one, see Base::is_block
+                if !self.done
+                    && self
+                        .base
+                        .pad_char()
+                        .is_some_and(|pad| self.chunk.ends_with(pad))
+                {
+                    self.done = true;
+                    return Some(Err(DecodeError::BadEncodedString));
+                }
+            }
+            None => {
+                self.chunk.extend(self.input.by_ref());
+                self.done = true;
+            }
+        }
+
+        let res = Decoder::decode_chunk(&self.chunk, &self.base, self.read, &mut self.decoded);
+        if res.is_err() {
+            // the bytes that were decoded before the error are dropped along with the rest
+            self.decoded.clear();
+            self.done = true;
+        }
+
+        Some(res)
+    }
+}
+
+#[cfg(feature = "decoding")]
+impl<I: Iterator<Item = char>> Iterator for DecodeIter<I> {
+    type Item = Result<u8, DecodeError>;
+
+    fn next(&mut self) -> Option<Result<u8, DecodeError>> {
+        while self.pos == self.decoded.len() {
+            self.decoded.clear();
+            if let Err(err) = self.refill()? {
+                return Some(Err(err));
+            }
+        }
+
+        let byte = self.decoded[self.pos];
+        self.pos += 1;
+
+        Some(Ok(byte))
+    }
+}
@@ -3,33 +3,96 @@
 #![cfg_attr(feature = "nightly", feature(doc_auto_cfg))]
 #![cfg_attr(feature = "nightly", feature(test))]
 
+// the simd kernels detect the cpu features at runtime, rayon's thread pool needs threads
+// and the async io traits work with std's io errors, all need std
+#[cfg(any(
+    feature = "simd",
+    feature = "rayon",
+    feature = "tokio",
+    feature = "futures-io"
+))]
+extern crate std;
+
 mod base_transformer;
-pub(crate) use base_transformer::BaseTransformer;
+#[cfg(any(feature = "base36", feature = "base58", feature = "base62"))]
+pub(crate) use base_transformer::BigBaseTransformer;
 
 mod decoders;
 mod encoders;
+mod simd;
+
+pub mod armor;
+pub mod bech32;
+pub mod ct;
+pub mod data_url;
+pub mod display;
+pub mod encoded_word;
+pub mod io;
+pub mod iter;
+pub mod literal;
+pub mod multibase;
+pub mod percent_encoding;
+pub mod quoted_printable;
+pub mod uuencode;
 
 pub use decoders::DecodeError;
 pub use decoders::Decoder;
 pub use encoders::Encoder;
+#[cfg(feature = "zeroize")]
+pub use zeroize::Zeroizing;
 
 pub(crate) const PAD: char = '=';
 
+/// the base 64 table as a byte array, for the const fns that can't go through `char_from_idx`
+#[cfg(feature = "base64")]
+pub(crate) const BASE64_TABLE: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
 pub const BASE64: Base = Base::_64;
 pub const BASE64URL: Base = Base::_64URL;
+pub const BASE64BCRYPT: Base = Base::_64BCRYPT;
+pub const BASE64CRYPT: Base = Base::_64CRYPT;
+pub const BASE64IMAP: Base = Base::_64IMAP;
+pub const BASE64XMLNAME: Base = Base::_64XMLNAME;
+pub const BASE64XMLNMTOKEN: Base = Base::_64XMLNMTOKEN;
+pub const BASE64Y64: Base = Base::_64Y64;
 pub const BASE32: Base = Base::_32;
 pub const BASE32HEX: Base = Base::_32HEX;
+pub const BASE32NSEC3: Base = Base::_32NSEC3;
+pub const BASE32Z: Base = Base::_32Z;
 pub const BASE16: Base = Base::_16;
 pub const BASE45: Base = Base::_45;
+pub const BASE36: Base = Base::_36;
+pub const BASE58: Base = Base::_58;
+pub const BASE62: Base = Base::_62;
+pub const BASE85: Base = Base::_85;
+pub const BASE85ASCII: Base = Base::_85ASCII;
+pub const BASE85Z: Base = Base::_85Z;
+pub const BASE91: Base = Base::_91;
 
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum Base {
     _64,
     _64URL,
+    _64BCRYPT,
+    _64CRYPT,
+    _64IMAP,
+    _64XMLNAME,
+    _64XMLNMTOKEN,
+    _64Y64,
     _45,
     _32,
     _32HEX,
+    _32NSEC3,
+    _32Z,
     _16,
+    _36,
+    _58,
+    _62,
+    _85,
+    _85ASCII,
+    _85Z,
+    _91,
 }
 
 impl core::fmt::Display for Base {
@@ -40,10 +103,25 @@ impl core::fmt::Display for Base {
             match self {
                 Self::_64 => "Base64",
                 Self::_64URL => "Base64URL",
+                Self::_64BCRYPT => "Base64BCRYPT",
+                Self::_64CRYPT => "Base64CRYPT",
+                Self::_64IMAP => "Base64IMAP",
+                Self::_64XMLNAME => "Base64XMLNAME",
+                Self::_64XMLNMTOKEN => "Base64XMLNMTOKEN",
+                Self::_64Y64 => "Base64Y64",
                 Self::_45 => "Base45",
                 Self::_32 => "Base32",
                 Self::_32HEX => "Base32HEX",
+                Self::_32NSEC3 => "Base32NSEC3",
+                Self::_32Z => "Base32Z",
                 Self::_16 => "Base16",
+                Self::_36 => "Base36",
+                Self::_58 => "Base58",
+                Self::_62 => "Base62",
+                Self::_85 => "Base85",
+                Self::_85ASCII => "Base85ASCII",
+                Self::_85Z => "Base85Z",
+                Self::_91 => "Base91",
             }
         )
     }
@@ -52,17 +130,42 @@ impl core::fmt::Display for Base {
 impl Base {
     // first 26 values of base encoding table are the uppercase alphabet letters A -> Z
     fn alpha_26(&self) -> bool {
-        self == &Self::_64 || self == &Self::_64URL || self == &Self::_32
+        self.is_any_64() || self == &Self::_32 || self == &Self::_91
+    }
+
+    // first 62 values of base encoding table are the uppercase alphabet letters A -> Z,
+    // the lowercase alphabet letters a -> z then the numbers 0 -> 9
+    fn alpha_num_62(&self) -> bool {
+        self.is_any_64() || self.is_91()
     }
 
     // first 16 values in the base encoding table are the base 16 numbers 0 -> F
     fn hex_16(&self) -> bool {
-        self == &Self::_32HEX || self == &Self::_16
+        self.is_32_hex() || self == &Self::_16
     }
 
-    // base is 64 or 64 url
+    // base is 64, 64 url or one of the base 64 profiles that only differ from them
+    // in the last 2 values of the encoding table
     fn is_any_64(&self) -> bool {
-        self == &Self::_64 || self == &Self::_64URL
+        matches!(
+            self,
+            Self::_64
+                | Self::_64URL
+                | Self::_64IMAP
+                | Self::_64XMLNAME
+                | Self::_64XMLNMTOKEN
+                | Self::_64Y64
+        )
+    }
+
+    // base is bcrypt's base 64; '.', '/' then A -> Z, a -> z and 0 -> 9
+    fn is_64_bcrypt(&self) -> bool {
+        self == &Self::_64BCRYPT
+    }
+
+    // base is crypt(3)'s base 64; '.', '/' then 0 -> 9, A -> Z and a -> z
+    fn is_64_crypt(&self) -> bool {
+        self == &Self::_64CRYPT
     }
 
     // base is strictly 32
@@ -70,14 +173,104 @@ impl Base {
         self == &Self::_32
     }
 
-    // base is strictly 32 hex
+    // base is 32 hex or its unpadded lowercase nsec3 profile
     fn is_32_hex(&self) -> bool {
-        self == &Self::_32HEX
+        self == &Self::_32HEX || self == &Self::_32NSEC3
+    }
+
+    // base is z-base-32
+    fn is_32_z(&self) -> bool {
+        self == &Self::_32Z
     }
 
     fn is_45(&self) -> bool {
         self == &Self::_45
     }
+
+    // first 36 values in the base encoding table are the numbers 0 -> 9 then the uppercase
+    // alphabet letters A -> Z
+    fn alnum_36(&self) -> bool {
+        self == &Self::_45 || self == &Self::_36 || self.alnum_62()
+    }
+
+    // first 62 values in the base encoding table are the numbers 0 -> 9,
+    // the uppercase alphabet letters A -> Z then the lowercase alphabet letters a -> z
+    fn alnum_62(&self) -> bool {
+        self == &Self::_62 || self == &Self::_85
+    }
+
+    // base is base58btc
+    fn is_58(&self) -> bool {
+        self == &Self::_58
+    }
+
+    // base is strictly 85 (rfc 1924)
+    fn is_85(&self) -> bool {
+        self == &Self::_85
+    }
+
+    // base is ascii85 (adobe / btoa)
+    fn is_85_ascii(&self) -> bool {
+        self == &Self::_85ASCII
+    }
+
+    // base is z85 (zeromq)
+    fn is_85_z(&self) -> bool {
+        self == &Self::_85Z
+    }
+
+    fn is_91(&self) -> bool {
+        self == &Self::_91
+    }
+
+    // the encoding table contains the '=' char, so it can't be mistaken for padding
+    fn has_eq_char(&self) -> bool {
+        self.is_85() || self.is_85_ascii() || self.is_85_z() || self.is_91()
+    }
+
+    // base encoding table is uppercase only, lowercase can be used in its place
+    fn is_case_insensitive(&self) -> bool {
+        self.hex_16() || self.is_32() || self == &Self::_36
+    }
+
+    /// returns true when the base is made of independent blocks, every few bytes are encoded
+    /// into a few chars without looking at the rest of the value
+    ///
+    /// only base 64 and its profiles, base 32 and its variants, base 16, base 45, base 85 and z85
+    /// are made of independent blocks; their padding can only come at the end of the whole value,
+    /// so a value can be split on block boundaries and its pieces worked on one at a time
+    ///
+    /// ```
+    /// assert!(makura::BASE64.is_block());
+    /// assert!(!makura::BASE58.is_block());
+    /// ```
+    pub fn is_block(&self) -> bool {
+        self.block_len().is_some()
+    }
+
+    // the size of the independent blocks of the base, (bytes, chars)
+    // every block of bytes is encoded into a block of chars without looking at its neighbours
+    // the big integer bases, ascii85's framing and base91's bit queue have no such blocks
+    pub(crate) fn block_len(&self) -> Option<(usize, usize)> {
+        match self {
+            _ if self.is_any_64() || self.is_64_bcrypt() || self.is_64_crypt() => Some((3, 4)),
+            _ if self.is_32() || self.is_32_hex() || self.is_32_z() => Some((5, 8)),
+            Self::_16 => Some((1, 2)),
+            Self::_45 => Some((2, 3)),
+            Self::_85 | Self::_85Z => Some((4, 5)),
+            _ => None,
+        }
+    }
+
+    // the padding char of the base encoding, if it has one
+    // y64 is the only one that doesn't pad with '=', since '=' is not url safe
+    pub(crate) fn pad_char(&self) -> Option<char> {
+        match self {
+            Self::_64 | Self::_64URL | Self::_32 | Self::_32HEX => Some(PAD),
+            Self::_64Y64 => Some('-'),
+            _ => None,
+        }
+    }
 }
 
 pub(crate) fn char_from_idx(idx: u8, base: &Base) -> char {
@@ -115,42 +308,42 @@ pub(crate) fn char_from_idx(idx: u8, base: &Base) -> char {
         24 if base.alpha_26() => 'Y',
         25 if base.alpha_26() => 'Z',
 
-        26 if base.is_any_64() => 'a',
-        27 if base.is_any_64() => 'b',
-        28 if base.is_any_64() => 'c',
-        29 if base.is_any_64() => 'd',
-        30 if base.is_any_64() => 'e',
-        31 if base.is_any_64() => 'f',
-        32 if base.is_any_64() => 'g',
-        33 if base.is_any_64() => 'h',
-        34 if base.is_any_64() => 'i',
-        35 if base.is_any_64() => 'j',
-        36 if base.is_any_64() => 'k',
-        37 if base.is_any_64() => 'l',
-        38 if base.is_any_64() => 'm',
-        39 if base.is_any_64() => 'n',
-        40 if base.is_any_64() => 'o',
-        41 if base.is_any_64() => 'p',
-        42 if base.is_any_64() => 'q',
-        43 if base.is_any_64() => 'r',
-        44 if base.is_any_64() => 's',
-        45 if base.is_any_64() => 't',
-        46 if base.is_any_64() => 'u',
-        47 if base.is_any_64() => 'v',
-        48 if base.is_any_64() => 'w',
-        49 if base.is_any_64() => 'x',
-        50 if base.is_any_64() => 'y',
-        51 if base.is_any_64() => 'z',
-        52 if base.is_any_64() => '0',
-        53 if base.is_any_64() => '1',
-        54 if base.is_any_64() => '2',
-        55 if base.is_any_64() => '3',
-        56 if base.is_any_64() => '4',
-        57 if base.is_any_64() => '5',
-        58 if base.is_any_64() => '6',
-        59 if base.is_any_64() => '7',
-        60 if base.is_any_64() => '8',
-        61 if base.is_any_64() => '9',
+        26 if base.alpha_num_62() => 'a',
+        27 if base.alpha_num_62() => 'b',
+        28 if base.alpha_num_62() => 'c',
+        29 if base.alpha_num_62() => 'd',
+        30 if base.alpha_num_62() => 'e',
+        31 if base.alpha_num_62() => 'f',
+        32 if base.alpha_num_62() => 'g',
+        33 if base.alpha_num_62() => 'h',
+        34 if base.alpha_num_62() => 'i',
+        35 if base.alpha_num_62() => 'j',
+        36 if base.alpha_num_62() => 'k',
+        37 if base.alpha_num_62() => 'l',
+        38 if base.alpha_num_62() => 'm',
+        39 if base.alpha_num_62() => 'n',
+        40 if base.alpha_num_62() => 'o',
+        41 if base.alpha_num_62() => 'p',
+        42 if base.alpha_num_62() => 'q',
+        43 if base.alpha_num_62() => 'r',
+        44 if base.alpha_num_62() => 's',
+        45 if base.alpha_num_62() => 't',
+        46 if base.alpha_num_62() => 'u',
+        47 if base.alpha_num_62() => 'v',
+        48 if base.alpha_num_62() => 'w',
+        49 if base.alpha_num_62() => 'x',
+        50 if base.alpha_num_62() => 'y',
+        51 if base.alpha_num_62() => 'z',
+        52 if base.alpha_num_62() => '0',
+        53 if base.alpha_num_62() => '1',
+        54 if base.alpha_num_62() => '2',
+        55 if base.alpha_num_62() => '3',
+        56 if base.alpha_num_62() => '4',
+        57 if base.alpha_num_62() => '5',
+        58 if base.alpha_num_62() => '6',
+        59 if base.alpha_num_62() => '7',
+        60 if base.alpha_num_62() => '8',
+        61 if base.alpha_num_62() => '9',
 
         // NOTE base 64 is done with this
         62 if base == &Base::_64 => '+',
@@ -160,6 +353,22 @@ pub(crate) fn char_from_idx(idx: u8, base: &Base) -> char {
         62 if base == &Base::_64URL => '-',
         63 if base == &Base::_64URL => '_',
 
+        // NOTE base 64 imap is done with this
+        62 if base == &Base::_64IMAP => '+',
+        63 if base == &Base::_64IMAP => ',',
+
+        // NOTE base 64 xml name is done with this
+        62 if base == &Base::_64XMLNAME => '_',
+        63 if base == &Base::_64XMLNAME => ':',
+
+        // NOTE base 64 xml nmtoken is done with this
+        62 if base == &Base::_64XMLNMTOKEN => '.',
+        63 if base == &Base::_64XMLNMTOKEN => '-',
+
+        // NOTE base 64 y64 is done with this
+        62 if base == &Base::_64Y64 => '.',
+        63 if base == &Base::_64Y64 => '_',
+
         // NOTE base 32 is done with tihs
         26 if base.is_32() => '2',
         27 if base.is_32() => '3',
@@ -170,45 +379,80 @@ pub(crate) fn char_from_idx(idx: u8, base: &Base) -> char {
 
         // hex
         // NOTE base 16 is done with this
-        0 if base.hex_16() | base.is_45() => '0',
-        1 if base.hex_16() | base.is_45() => '1',
-        2 if base.hex_16() | base.is_45() => '2',
-        3 if base.hex_16() | base.is_45() => '3',
-        4 if base.hex_16() | base.is_45() => '4',
-        5 if base.hex_16() | base.is_45() => '5',
-        6 if base.hex_16() | base.is_45() => '6',
-        7 if base.hex_16() | base.is_45() => '7',
-        8 if base.hex_16() | base.is_45() => '8',
-        9 if base.hex_16() | base.is_45() => '9',
-        10 if base.hex_16() | base.is_45() => 'A',
-        11 if base.hex_16() | base.is_45() => 'B',
-        12 if base.hex_16() | base.is_45() => 'C',
-        13 if base.hex_16() | base.is_45() => 'D',
-        14 if base.hex_16() | base.is_45() => 'E',
-        15 if base.hex_16() | base.is_45() => 'F',
+        0 if base.hex_16() | base.alnum_36() => '0',
+        1 if base.hex_16() | base.alnum_36() => '1',
+        2 if base.hex_16() | base.alnum_36() => '2',
+        3 if base.hex_16() | base.alnum_36() => '3',
+        4 if base.hex_16() | base.alnum_36() => '4',
+        5 if base.hex_16() | base.alnum_36() => '5',
+        6 if base.hex_16() | base.alnum_36() => '6',
+        7 if base.hex_16() | base.alnum_36() => '7',
+        8 if base.hex_16() | base.alnum_36() => '8',
+        9 if base.hex_16() | base.alnum_36() => '9',
+        10 if base.hex_16() | base.alnum_36() => 'A',
+        11 if base.hex_16() | base.alnum_36() => 'B',
+        12 if base.hex_16() | base.alnum_36() => 'C',
+        13 if base.hex_16() | base.alnum_36() => 'D',
+        14 if base.hex_16() | base.alnum_36() => 'E',
+        15 if base.hex_16() | base.alnum_36() => 'F',
 
         // NOTE base 32 hex is done with this
-        16 if base.is_32_hex() | base.is_45() => 'G',
-        17 if base.is_32_hex() | base.is_45() => 'H',
-        18 if base.is_32_hex() | base.is_45() => 'I',
-        19 if base.is_32_hex() | base.is_45() => 'J',
-        20 if base.is_32_hex() | base.is_45() => 'K',
-        21 if base.is_32_hex() | base.is_45() => 'L',
-        22 if base.is_32_hex() | base.is_45() => 'M',
-        23 if base.is_32_hex() | base.is_45() => 'N',
-        24 if base.is_32_hex() | base.is_45() => 'O',
-        25 if base.is_32_hex() | base.is_45() => 'P',
-        26 if base.is_32_hex() | base.is_45() => 'Q',
-        27 if base.is_32_hex() | base.is_45() => 'R',
-        28 if base.is_32_hex() | base.is_45() => 'S',
-        29 if base.is_32_hex() | base.is_45() => 'T',
-        30 if base.is_32_hex() | base.is_45() => 'U',
-        31 if base.is_32_hex() | base.is_45() => 'V',
-
-        32 if base.is_45() => 'W',
-        33 if base.is_45() => 'X',
-        34 if base.is_45() => 'Y',
-        35 if base.is_45() => 'Z',
+        16 if base.is_32_hex() | base.alnum_36() => 'G',
+        17 if base.is_32_hex() | base.alnum_36() => 'H',
+        18 if base.is_32_hex() | base.alnum_36() => 'I',
+        19 if base.is_32_hex() | base.alnum_36() => 'J',
+        20 if base.is_32_hex() | base.alnum_36() => 'K',
+        21 if base.is_32_hex() | base.alnum_36() => 'L',
+        22 if base.is_32_hex() | base.alnum_36() => 'M',
+        23 if base.is_32_hex() | base.alnum_36() => 'N',
+        24 if base.is_32_hex() | base.alnum_36() => 'O',
+        25 if base.is_32_hex() | base.alnum_36() => 'P',
+        26 if base.is_32_hex() | base.alnum_36() => 'Q',
+        27 if base.is_32_hex() | base.alnum_36() => 'R',
+        28 if base.is_32_hex() | base.alnum_36() => 'S',
+        29 if base.is_32_hex() | base.alnum_36() => 'T',
+        30 if base.is_32_hex() | base.alnum_36() => 'U',
+        31 if base.is_32_hex() | base.alnum_36() => 'V',
+
+        // z-base-32
+        // NOTE base 32 z is done with this
+        0 if base.is_32_z() => 'y',
+        1 if base.is_32_z() => 'b',
+        2 if base.is_32_z() => 'n',
+        3 if base.is_32_z() => 'd',
+        4 if base.is_32_z() => 'r',
+        5 if base.is_32_z() => 'f',
+        6 if base.is_32_z() => 'g',
+        7 if base.is_32_z() => '8',
+        8 if base.is_32_z() => 'e',
+        9 if base.is_32_z() => 'j',
+        10 if base.is_32_z() => 'k',
+        11 if base.is_32_z() => 'm',
+        12 if base.is_32_z() => 'c',
+        13 if base.is_32_z() => 'p',
+        14 if base.is_32_z() => 'q',
+        15 if base.is_32_z() => 'x',
+        16 if base.is_32_z() => 'o',
+        17 if base.is_32_z() => 't',
+        18 if base.is_32_z() => '1',
+        19 if base.is_32_z() => 'u',
+        20 if base.is_32_z() => 'w',
+        21 if base.is_32_z() => 'i',
+        22 if base.is_32_z() => 's',
+        23 if base.is_32_z() => 'z',
+        24 if base.is_32_z() => 'a',
+        25 if base.is_32_z() => '3',
+        26 if base.is_32_z() => '4',
+        27 if base.is_32_z() => '5',
+        28 if base.is_32_z() => 'h',
+        29 if base.is_32_z() => '7',
+        30 if base.is_32_z() => '6',
+        31 if base.is_32_z() => '9',
+
+        32 if base.alnum_36() => 'W',
+        33 if base.alnum_36() => 'X',
+        34 if base.alnum_36() => 'Y',
+        35 if base.alnum_36() => 'Z',
         36 if base.is_45() => ' ',
         37 if base.is_45() => '$',
         38 if base.is_45() => '%',
@@ -219,6 +463,255 @@ pub(crate) fn char_from_idx(idx: u8, base: &Base) -> char {
         43 if base.is_45() => '/',
         44 if base.is_45() => ':',
 
+        // NOTE base 36 is done with the first 36 values of base 45
+
+        // base58btc
+        // NOTE base 58 is done with this
+        0 if base.is_58() => '1',
+        1 if base.is_58() => '2',
+        2 if base.is_58() => '3',
+        3 if base.is_58() => '4',
+        4 if base.is_58() => '5',
+        5 if base.is_58() => '6',
+        6 if base.is_58() => '7',
+        7 if base.is_58() => '8',
+        8 if base.is_58() => '9',
+        9 if base.is_58() => 'A',
+        10 if base.is_58() => 'B',
+        11 if base.is_58() => 'C',
+        12 if base.is_58() => 'D',
+        13 if base.is_58() => 'E',
+        14 if base.is_58() => 'F',
+        15 if base.is_58() => 'G',
+        16 if base.is_58() => 'H',
+        17 if base.is_58() => 'J',
+        18 if base.is_58() => 'K',
+        19 if base.is_58() => 'L',
+        20 if base.is_58() => 'M',
+        21 if base.is_58() => 'N',
+        22 if base.is_58() => 'P',
+        23 if base.is_58() => 'Q',
+        24 if base.is_58() => 'R',
+        25 if base.is_58() => 'S',
+        26 if base.is_58() => 'T',
+        27 if base.is_58() => 'U',
+        28 if base.is_58() => 'V',
+        29 if base.is_58() => 'W',
+        30 if base.is_58() => 'X',
+        31 if base.is_58() => 'Y',
+        32 if base.is_58() => 'Z',
+        33 if base.is_58() => 'a',
+        34 if base.is_58() => 'b',
+        35 if base.is_58() => 'c',
+        36 if base.is_58() => 'd',
+        37 if base.is_58() => 'e',
+        38 if base.is_58() => 'f',
+        39 if base.is_58() => 'g',
+        40 if base.is_58() => 'h',
+        41 if base.is_58() => 'i',
+        42 if base.is_58() => 'j',
+        43 if base.is_58() => 'k',
+        44 if base.is_58() => 'm',
+        45 if base.is_58() => 'n',
+        46 if base.is_58() => 'o',
+        47 if base.is_58() => 'p',
+        48 if base.is_58() => 'q',
+        49 if base.is_58() => 'r',
+        50 if base.is_58() => 's',
+        51 if base.is_58() => 't',
+        52 if base.is_58() => 'u',
+        53 if base.is_58() => 'v',
+        54 if base.is_58() => 'w',
+        55 if base.is_58() => 'x',
+        56 if base.is_58() => 'y',
+        57 if base.is_58() => 'z',
+
+        // NOTE base 62 is done with this
+        36 if base.alnum_62() => 'a',
+        37 if base.alnum_62() => 'b',
+        38 if base.alnum_62() => 'c',
+        39 if base.alnum_62() => 'd',
+        40 if base.alnum_62() => 'e',
+        41 if base.alnum_62() => 'f',
+        42 if base.alnum_62() => 'g',
+        43 if base.alnum_62() => 'h',
+        44 if base.alnum_62() => 'i',
+        45 if base.alnum_62() => 'j',
+        46 if base.alnum_62() => 'k',
+        47 if base.alnum_62() => 'l',
+        48 if base.alnum_62() => 'm',
+        49 if base.alnum_62() => 'n',
+        50 if base.alnum_62() => 'o',
+        51 if base.alnum_62() => 'p',
+        52 if base.alnum_62() => 'q',
+        53 if base.alnum_62() => 'r',
+        54 if base.alnum_62() => 's',
+        55 if base.alnum_62() => 't',
+        56 if base.alnum_62() => 'u',
+        57 if base.alnum_62() => 'v',
+        58 if base.alnum_62() => 'w',
+        59 if base.alnum_62() => 'x',
+        60 if base.alnum_62() => 'y',
+        61 if base.alnum_62() => 'z',
+
+        // NOTE base 85 (rfc 1924) is done with this
+        62 if base.is_85() => '!',
+        63 if base.is_85() => '#',
+        64 if base.is_85() => '$',
+        65 if base.is_85() => '%',
+        66 if base.is_85() => '&',
+        67 if base.is_85() => '(',
+        68 if base.is_85() => ')',
+        69 if base.is_85() => '*',
+        70 if base.is_85() => '+',
+        71 if base.is_85() => '-',
+        72 if base.is_85() => ';',
+        73 if base.is_85() => '<',
+        74 if base.is_85() => '=',
+        75 if base.is_85() => '>',
+        76 if base.is_85() => '?',
+        77 if base.is_85() => '@',
+        78 if base.is_85() => '^',
+        79 if base.is_85() => '_',
+        80 if base.is_85() => '`',
+        81 if base.is_85() => '{',
+        82 if base.is_85() => '|',
+        83 if base.is_85() => '}',
+        84 if base.is_85() => '~',
+
+        // NOTE base 85 ascii is a contiguous range of the ascii table; '!' -> 'u'
+        idx if base.is_85_ascii() && idx < 85 => (idx + b'!') as char,
+
+        // z85
+        // NOTE base 85 z is done with this
+        0 if base.is_85_z() => '0',
+        1 if base.is_85_z() => '1',
+        2 if base.is_85_z() => '2',
+        3 if base.is_85_z() => '3',
+        4 if base.is_85_z() => '4',
+        5 if base.is_85_z() => '5',
+        6 if base.is_85_z() => '6',
+        7 if base.is_85_z() => '7',
+        8 if base.is_85_z() => '8',
+        9 if base.is_85_z() => '9',
+        10 if base.is_85_z() => 'a',
+        11 if base.is_85_z() => 'b',
+        12 if base.is_85_z() => 'c',
+        13 if base.is_85_z() => 'd',
+        14 if base.is_85_z() => 'e',
+        15 if base.is_85_z() => 'f',
+        16 if base.is_85_z() => 'g',
+        17 if base.is_85_z() => 'h',
+        18 if base.is_85_z() => 'i',
+        19 if base.is_85_z() => 'j',
+        20 if base.is_85_z() => 'k',
+        21 if base.is_85_z() => 'l',
+        22 if base.is_85_z() => 'm',
+        23 if base.is_85_z() => 'n',
+        24 if base.is_85_z() => 'o',
+        25 if base.is_85_z() => 'p',
+        26 if base.is_85_z() => 'q',
+        27 if base.is_85_z() => 'r',
+        28 if base.is_85_z() => 's',
+        29 if base.is_85_z() => 't',
+        30 if base.is_85_z() => 'u',
+        31 if base.is_85_z() => 'v',
+        32 if base.is_85_z() => 'w',
+        33 if base.is_85_z() => 'x',
+        34 if base.is_85_z() => 'y',
+        35 if base.is_85_z() => 'z',
+        36 if base.is_85_z() => 'A',
+        37 if base.is_85_z() => 'B',
+        38 if base.is_85_z() => 'C',
+        39 if base.is_85_z() => 'D',
+        40 if base.is_85_z() => 'E',
+        41 if base.is_85_z() => 'F',
+        42 if base.is_85_z() => 'G',
+        43 if base.is_85_z() => 'H',
+        44 if base.is_85_z() => 'I',
+        45 if base.is_85_z() => 'J',
+        46 if base.is_85_z() => 'K',
+        47 if base.is_85_z() => 'L',
+        48 if base.is_85_z() => 'M',
+        49 if base.is_85_z() => 'N',
+        50 if base.is_85_z() => 'O',
+        51 if base.is_85_z() => 'P',
+        52 if base.is_85_z() => 'Q',
+        53 if base.is_85_z() => 'R',
+        54 if base.is_85_z() => 'S',
+        55 if base.is_85_z() => 'T',
+        56 if base.is_85_z() => 'U',
+        57 if base.is_85_z() => 'V',
+        58 if base.is_85_z() => 'W',
+        59 if base.is_85_z() => 'X',
+        60 if base.is_85_z() => 'Y',
+        61 if base.is_85_z() => 'Z',
+        62 if base.is_85_z() => '.',
+        63 if base.is_85_z() => '-',
+        64 if base.is_85_z() => ':',
+        65 if base.is_85_z() => '+',
+        66 if base.is_85_z() => '=',
+        67 if base.is_85_z() => '^',
+        68 if base.is_85_z() => '!',
+        69 if base.is_85_z() => '/',
+        70 if base.is_85_z() => '*',
+        71 if base.is_85_z() => '?',
+        72 if base.is_85_z() => '&',
+        73 if base.is_85_z() => '<',
+        74 if base.is_85_z() => '>',
+        75 if base.is_85_z() => '(',
+        76 if base.is_85_z() => ')',
+        77 if base.is_85_z() => '[',
+        78 if base.is_85_z() => ']',
+        79 if base.is_85_z() => '{',
+        80 if base.is_85_z() => '}',
+        81 if base.is_85_z() => '@',
+        82 if base.is_85_z() => '%',
+        83 if base.is_85_z() => '$',
+        84 if base.is_85_z() => '#',
+
+        // NOTE base 91 is done with this
+        62 if base.is_91() => '!',
+        63 if base.is_91() => '#',
+        64 if base.is_91() => '$',
+        65 if base.is_91() => '%',
+        66 if base.is_91() => '&',
+        67 if base.is_91() => '(',
+        68 if base.is_91() => ')',
+        69 if base.is_91() => '*',
+        70 if base.is_91() => '+',
+        71 if base.is_91() => ',',
+        72 if base.is_91() => '.',
+        73 if base.is_91() => '/',
+        74 if base.is_91() => ':',
+        75 if base.is_91() => ';',
+        76 if base.is_91() => '<',
+        77 if base.is_91() => '=',
+        78 if base.is_91() => '>',
+        79 if base.is_91() => '?',
+        80 if base.is_91() => '@',
+        81 if base.is_91() => '[',
+        82 if base.is_91() => ']',
+        83 if base.is_91() => '^',
+        84 if base.is_91() => '_',
+        85 if base.is_91() => '`',
+        86 if base.is_91() => '{',
+        87 if base.is_91() => '|',
+        88 if base.is_91() => '}',
+        89 if base.is_91() => '~',
+        90 if base.is_91() => '"',
+
+        // NOTE base 64 bcrypt and crypt are contiguous ranges of the ascii table
+        // that only differ in the order of the ranges
+        0 if base.is_64_bcrypt() | base.is_64_crypt() => '.',
+        1 if base.is_64_bcrypt() | base.is_64_crypt() => '/',
+        idx @ 2..=27 if base.is_64_bcrypt() => (idx - 2 + b'A') as char,
+        idx @ 28..=53 if base.is_64_bcrypt() => (idx - 28 + b'a') as char,
+        idx @ 54..=63 if base.is_64_bcrypt() => (idx - 54 + b'0') as char,
+        idx @ 2..=11 if base.is_64_crypt() => (idx - 2 + b'0') as char,
+        idx @ 12..=37 if base.is_64_crypt() => (idx - 12 + b'A') as char,
+        idx @ 38..=63 if base.is_64_crypt() => (idx - 38 + b'a') as char,
+
         // NOTE let this panic, since this crate is always in control of the u8 that
         // this function receives, guarenteeing that this match arm is always unreachable
         idx => unreachable!("got impossile table index {} for base {:?}", idx, base),
@@ -226,7 +719,15 @@ pub(crate) fn char_from_idx(idx: u8, base: &Base) -> char {
 }
 
 pub(crate) fn idx_from_char(chr: char, base: &Base) -> Result<u8, DecodeError> {
-    match chr {
+    // the base 16, base 32 and base 36 tables have no lowercase chars,
+    // so lowercase chars are decoded the same as their uppercase counterparts
+    let upper = if base.is_case_insensitive() {
+        chr.to_ascii_uppercase()
+    } else {
+        chr
+    };
+
+    match upper {
         // alpha
         'A' if base.alpha_26() => Ok(0),
         'B' if base.alpha_26() => Ok(1),
@@ -255,42 +756,42 @@ pub(crate) fn idx_from_char(chr: char, base: &Base) -> Result<u8, DecodeError> {
         'Y' if base.alpha_26() => Ok(24),
         'Z' if base.alpha_26() => Ok(25),
 
-        'a' if base.is_any_64() => Ok(26),
-        'b' if base.is_any_64() => Ok(27),
-        'c' if base.is_any_64() => Ok(28),
-        'd' if base.is_any_64() => Ok(29),
-        'e' if base.is_any_64() => Ok(30),
-        'f' if base.is_any_64() => Ok(31),
-        'g' if base.is_any_64() => Ok(32),
-        'h' if base.is_any_64() => Ok(33),
-        'i' if base.is_any_64() => Ok(34),
-        'j' if base.is_any_64() => Ok(35),
-        'k' if base.is_any_64() => Ok(36),
-        'l' if base.is_any_64() => Ok(37),
-        'm' if base.is_any_64() => Ok(38),
-        'n' if base.is_any_64() => Ok(39),
-        'o' if base.is_any_64() => Ok(40),
-        'p' if base.is_any_64() => Ok(41),
-        'q' if base.is_any_64() => Ok(42),
-        'r' if base.is_any_64() => Ok(43),
-        's' if base.is_any_64() => Ok(44),
-        't' if base.is_any_64() => Ok(45),
-        'u' if base.is_any_64() => Ok(46),
-        'v' if base.is_any_64() => Ok(47),
-        'w' if base.is_any_64() => Ok(48),
-        'x' if base.is_any_64() => Ok(49),
-        'y' if base.is_any_64() => Ok(50),
-        'z' if base.is_any_64() => Ok(51),
-        '0' if base.is_any_64() => Ok(52),
-        '1' if base.is_any_64() => Ok(53),
-        '2' if base.is_any_64() => Ok(54),
-        '3' if base.is_any_64() => Ok(55),
-        '4' if base.is_any_64() => Ok(56),
-        '5' if base.is_any_64() => Ok(57),
-        '6' if base.is_any_64() => Ok(58),
-        '7' if base.is_any_64() => Ok(59),
-        '8' if base.is_any_64() => Ok(60),
-        '9' if base.is_any_64() => Ok(61),
+        'a' if base.alpha_num_62() => Ok(26),
+        'b' if base.alpha_num_62() => Ok(27),
+        'c' if base.alpha_num_62() => Ok(28),
+        'd' if base.alpha_num_62() => Ok(29),
+        'e' if base.alpha_num_62() => Ok(30),
+        'f' if base.alpha_num_62() => Ok(31),
+        'g' if base.alpha_num_62() => Ok(32),
+        'h' if base.alpha_num_62() => Ok(33),
+        'i' if base.alpha_num_62() => Ok(34),
+        'j' if base.alpha_num_62() => Ok(35),
+        'k' if base.alpha_num_62() => Ok(36),
+        'l' if base.alpha_num_62() => Ok(37),
+        'm' if base.alpha_num_62() => Ok(38),
+        'n' if base.alpha_num_62() => Ok(39),
+        'o' if base.alpha_num_62() => Ok(40),
+        'p' if base.alpha_num_62() => Ok(41),
+        'q' if base.alpha_num_62() => Ok(42),
+        'r' if base.alpha_num_62() => Ok(43),
+        's' if base.alpha_num_62() => Ok(44),
+        't' if base.alpha_num_62() => Ok(45),
+        'u' if base.alpha_num_62() => Ok(46),
+        'v' if base.alpha_num_62() => Ok(47),
+        'w' if base.alpha_num_62() => Ok(48),
+        'x' if base.alpha_num_62() => Ok(49),
+        'y' if base.alpha_num_62() => Ok(50),
+        'z' if base.alpha_num_62() => Ok(51),
+        '0' if base.alpha_num_62() => Ok(52),
+        '1' if base.alpha_num_62() => Ok(53),
+        '2' if base.alpha_num_62() => Ok(54),
+        '3' if base.alpha_num_62() => Ok(55),
+        '4' if base.alpha_num_62() => Ok(56),
+        '5' if base.alpha_num_62() => Ok(57),
+        '6' if base.alpha_num_62() => Ok(58),
+        '7' if base.alpha_num_62() => Ok(59),
+        '8' if base.alpha_num_62() => Ok(60),
+        '9' if base.alpha_num_62() => Ok(61),
 
         // NOTE base 64 is done with this
         '+' if base == &Base::_64 => Ok(62),
@@ -300,6 +801,22 @@ pub(crate) fn idx_from_char(chr: char, base: &Base) -> Result<u8, DecodeError> {
         '-' if base == &Base::_64URL => Ok(62),
         '_' if base == &Base::_64URL => Ok(63),
 
+        // NOTE base 64 imap is done with this
+        '+' if base == &Base::_64IMAP => Ok(62),
+        ',' if base == &Base::_64IMAP => Ok(63),
+
+        // NOTE base 64 xml name is done with this
+        '_' if base == &Base::_64XMLNAME => Ok(62),
+        ':' if base == &Base::_64XMLNAME => Ok(63),
+
+        // NOTE base 64 xml nmtoken is done with this
+        '.' if base == &Base::_64XMLNMTOKEN => Ok(62),
+        '-' if base == &Base::_64XMLNMTOKEN => Ok(63),
+
+        // NOTE base 64 y64 is done with this
+        '.' if base == &Base::_64Y64 => Ok(62),
+        '_' if base == &Base::_64Y64 => Ok(63),
+
         // NOTE base 32 is done with this
         '2' if base.is_32() => Ok(26),
         '3' if base.is_32() => Ok(27),
@@ -310,45 +827,80 @@ pub(crate) fn idx_from_char(chr: char, base: &Base) -> Result<u8, DecodeError> {
 
         // hex
         // NOTE base 16 is done with this
-        '0' if base.hex_16() | base.is_45() => Ok(0),
-        '1' if base.hex_16() | base.is_45() => Ok(1),
-        '2' if base.hex_16() | base.is_45() => Ok(2),
-        '3' if base.hex_16() | base.is_45() => Ok(3),
-        '4' if base.hex_16() | base.is_45() => Ok(4),
-        '5' if base.hex_16() | base.is_45() => Ok(5),
-        '6' if base.hex_16() | base.is_45() => Ok(6),
-        '7' if base.hex_16() | base.is_45() => Ok(7),
-        '8' if base.hex_16() | base.is_45() => Ok(8),
-        '9' if base.hex_16() | base.is_45() => Ok(9),
-        'A' if base.hex_16() | base.is_45() => Ok(10),
-        'B' if base.hex_16() | base.is_45() => Ok(11),
-        'C' if base.hex_16() | base.is_45() => Ok(12),
-        'D' if base.hex_16() | base.is_45() => Ok(13),
-        'E' if base.hex_16() | base.is_45() => Ok(14),
-        'F' if base.hex_16() | base.is_45() => Ok(15),
+        '0' if base.hex_16() | base.alnum_36() => Ok(0),
+        '1' if base.hex_16() | base.alnum_36() => Ok(1),
+        '2' if base.hex_16() | base.alnum_36() => Ok(2),
+        '3' if base.hex_16() | base.alnum_36() => Ok(3),
+        '4' if base.hex_16() | base.alnum_36() => Ok(4),
+        '5' if base.hex_16() | base.alnum_36() => Ok(5),
+        '6' if base.hex_16() | base.alnum_36() => Ok(6),
+        '7' if base.hex_16() | base.alnum_36() => Ok(7),
+        '8' if base.hex_16() | base.alnum_36() => Ok(8),
+        '9' if base.hex_16() | base.alnum_36() => Ok(9),
+        'A' if base.hex_16() | base.alnum_36() => Ok(10),
+        'B' if base.hex_16() | base.alnum_36() => Ok(11),
+        'C' if base.hex_16() | base.alnum_36() => Ok(12),
+        'D' if base.hex_16() | base.alnum_36() => Ok(13),
+        'E' if base.hex_16() | base.alnum_36() => Ok(14),
+        'F' if base.hex_16() | base.alnum_36() => Ok(15),
 
         // NOTE base 32 hex is done with this
-        'G' if base.is_32_hex() | base.is_45() => Ok(16),
-        'H' if base.is_32_hex() | base.is_45() => Ok(17),
-        'I' if base.is_32_hex() | base.is_45() => Ok(18),
-        'J' if base.is_32_hex() | base.is_45() => Ok(19),
-        'K' if base.is_32_hex() | base.is_45() => Ok(20),
-        'L' if base.is_32_hex() | base.is_45() => Ok(21),
-        'M' if base.is_32_hex() | base.is_45() => Ok(22),
-        'N' if base.is_32_hex() | base.is_45() => Ok(23),
-        'O' if base.is_32_hex() | base.is_45() => Ok(24),
-        'P' if base.is_32_hex() | base.is_45() => Ok(25),
-        'Q' if base.is_32_hex() | base.is_45() => Ok(26),
-        'R' if base.is_32_hex() | base.is_45() => Ok(27),
-        'S' if base.is_32_hex() | base.is_45() => Ok(28),
-        'T' if base.is_32_hex() | base.is_45() => Ok(29),
-        'U' if base.is_32_hex() | base.is_45() => Ok(30),
-        'V' if base.is_32_hex() | base.is_45() => Ok(31),
-
-        'W' if base.is_45() => Ok(32),
-        'X' if base.is_45() => Ok(33),
-        'Y' if base.is_45() => Ok(34),
-        'Z' if base.is_45() => Ok(35),
+        'G' if base.is_32_hex() | base.alnum_36() => Ok(16),
+        'H' if base.is_32_hex() | base.alnum_36() => Ok(17),
+        'I' if base.is_32_hex() | base.alnum_36() => Ok(18),
+        'J' if base.is_32_hex() | base.alnum_36() => Ok(19),
+        'K' if base.is_32_hex() | base.alnum_36() => Ok(20),
+        'L' if base.is_32_hex() | base.alnum_36() => Ok(21),
+        'M' if base.is_32_hex() | base.alnum_36() => Ok(22),
+        'N' if base.is_32_hex() | base.alnum_36() => Ok(23),
+        'O' if base.is_32_hex() | base.alnum_36() => Ok(24),
+        'P' if base.is_32_hex() | base.alnum_36() => Ok(25),
+        'Q' if base.is_32_hex() | base.alnum_36() => Ok(26),
+        'R' if base.is_32_hex() | base.alnum_36() => Ok(27),
+        'S' if base.is_32_hex() | base.alnum_36() => Ok(28),
+        'T' if base.is_32_hex() | base.alnum_36() => Ok(29),
+        'U' if base.is_32_hex() | base.alnum_36() => Ok(30),
+        'V' if base.is_32_hex() | base.alnum_36() => Ok(31),
+
+        // z-base-32
+        // NOTE base 32 z is done with this
+        'y' if base.is_32_z() => Ok(0),
+        'b' if base.is_32_z() => Ok(1),
+        'n' if base.is_32_z() => Ok(2),
+        'd' if base.is_32_z() => Ok(3),
+        'r' if base.is_32_z() => Ok(4),
+        'f' if base.is_32_z() => Ok(5),
+        'g' if base.is_32_z() => Ok(6),
+        '8' if base.is_32_z() => Ok(7),
+        'e' if base.is_32_z() => Ok(8),
+        'j' if base.is_32_z() => Ok(9),
+        'k' if base.is_32_z() => Ok(10),
+        'm' if base.is_32_z() => Ok(11),
+        'c' if base.is_32_z() => Ok(12),
+        'p' if base.is_32_z() => Ok(13),
+        'q' if base.is_32_z() => Ok(14),
+        'x' if base.is_32_z() => Ok(15),
+        'o' if base.is_32_z() => Ok(16),
+        't' if base.is_32_z() => Ok(17),
+        '1' if base.is_32_z() => Ok(18),
+        'u' if base.is_32_z() => Ok(19),
+        'w' if base.is_32_z() => Ok(20),
+        'i' if base.is_32_z() => Ok(21),
+        's' if base.is_32_z() => Ok(22),
+        'z' if base.is_32_z() => Ok(23),
+        'a' if base.is_32_z() => Ok(24),
+        '3' if base.is_32_z() => Ok(25),
+        '4' if base.is_32_z() => Ok(26),
+        '5' if base.is_32_z() => Ok(27),
+        'h' if base.is_32_z() => Ok(28),
+        '7' if base.is_32_z() => Ok(29),
+        '6' if base.is_32_z() => Ok(30),
+        '9' if base.is_32_z() => Ok(31),
+
+        'W' if base.alnum_36() => Ok(32),
+        'X' if base.alnum_36() => Ok(33),
+        'Y' if base.alnum_36() => Ok(34),
+        'Z' if base.alnum_36() => Ok(35),
         ' ' if base.is_45() => Ok(36),
         '$' if base.is_45() => Ok(37),
         '%' if base.is_45() => Ok(38),
@@ -359,11 +911,312 @@ pub(crate) fn idx_from_char(chr: char, base: &Base) -> Result<u8, DecodeError> {
         '/' if base.is_45() => Ok(43),
         ':' if base.is_45() => Ok(44),
 
+        // NOTE base 36 is done with the first 36 values of base 45
+
+        // base58btc
+        // NOTE base 58 is done with this
+        '1' if base.is_58() => Ok(0),
+        '2' if base.is_58() => Ok(1),
+        '3' if base.is_58() => Ok(2),
+        '4' if base.is_58() => Ok(3),
+        '5' if base.is_58() => Ok(4),
+        '6' if base.is_58() => Ok(5),
+        '7' if base.is_58() => Ok(6),
+        '8' if base.is_58() => Ok(7),
+        '9' if base.is_58() => Ok(8),
+        'A' if base.is_58() => Ok(9),
+        'B' if base.is_58() => Ok(10),
+        'C' if base.is_58() => Ok(11),
+        'D' if base.is_58() => Ok(12),
+        'E' if base.is_58() => Ok(13),
+        'F' if base.is_58() => Ok(14),
+        'G' if base.is_58() => Ok(15),
+        'H' if base.is_58() => Ok(16),
+        'J' if base.is_58() => Ok(17),
+        'K' if base.is_58() => Ok(18),
+        'L' if base.is_58() => Ok(19),
+        'M' if base.is_58() => Ok(20),
+        'N' if base.is_58() => Ok(21),
+        'P' if base.is_58() => Ok(22),
+        'Q' if base.is_58() => Ok(23),
+        'R' if base.is_58() => Ok(24),
+        'S' if base.is_58() => Ok(25),
+        'T' if base.is_58() => Ok(26),
+        'U' if base.is_58() => Ok(27),
+        'V' if base.is_58() => Ok(28),
+        'W' if base.is_58() => Ok(29),
+        'X' if base.is_58() => Ok(30),
+        'Y' if base.is_58() => Ok(31),
+        'Z' if base.is_58() => Ok(32),
+        'a' if base.is_58() => Ok(33),
+        'b' if base.is_58() => Ok(34),
+        'c' if base.is_58() => Ok(35),
+        'd' if base.is_58() => Ok(36),
+        'e' if base.is_58() => Ok(37),
+        'f' if base.is_58() => Ok(38),
+        'g' if base.is_58() => Ok(39),
+        'h' if base.is_58() => Ok(40),
+        'i' if base.is_58() => Ok(41),
+        'j' if base.is_58() => Ok(42),
+        'k' if base.is_58() => Ok(43),
+        'm' if base.is_58() => Ok(44),
+        'n' if base.is_58() => Ok(45),
+        'o' if base.is_58() => Ok(46),
+        'p' if base.is_58() => Ok(47),
+        'q' if base.is_58() => Ok(48),
+        'r' if base.is_58() => Ok(49),
+        's' if base.is_58() => Ok(50),
+        't' if base.is_58() => Ok(51),
+        'u' if base.is_58() => Ok(52),
+        'v' if base.is_58() => Ok(53),
+        'w' if base.is_58() => Ok(54),
+        'x' if base.is_58() => Ok(55),
+        'y' if base.is_58() => Ok(56),
+        'z' if base.is_58() => Ok(57),
+
+        // NOTE base 62 is done with this
+        'a' if base.alnum_62() => Ok(36),
+        'b' if base.alnum_62() => Ok(37),
+        'c' if base.alnum_62() => Ok(38),
+        'd' if base.alnum_62() => Ok(39),
+        'e' if base.alnum_62() => Ok(40),
+        'f' if base.alnum_62() => Ok(41),
+        'g' if base.alnum_62() => Ok(42),
+        'h' if base.alnum_62() => Ok(43),
+        'i' if base.alnum_62() => Ok(44),
+        'j' if base.alnum_62() => Ok(45),
+        'k' if base.alnum_62() => Ok(46),
+        'l' if base.alnum_62() => Ok(47),
+        'm' if base.alnum_62() => Ok(48),
+        'n' if base.alnum_62() => Ok(49),
+        'o' if base.alnum_62() => Ok(50),
+        'p' if base.alnum_62() => Ok(51),
+        'q' if base.alnum_62() => Ok(52),
+        'r' if base.alnum_62() => Ok(53),
+        's' if base.alnum_62() => Ok(54),
+        't' if base.alnum_62() => Ok(55),
+        'u' if base.alnum_62() => Ok(56),
+        'v' if base.alnum_62() => Ok(57),
+        'w' if base.alnum_62() => Ok(58),
+        'x' if base.alnum_62() => Ok(59),
+        'y' if base.alnum_62() => Ok(60),
+        'z' if base.alnum_62() => Ok(61),
+
+        // NOTE base 85 (rfc 1924) is done with this
+        '!' if base.is_85() => Ok(62),
+        '#' if base.is_85() => Ok(63),
+        '$' if base.is_85() => Ok(64),
+        '%' if base.is_85() => Ok(65),
+        '&' if base.is_85() => Ok(66),
+        '(' if base.is_85() => Ok(67),
+        ')' if base.is_85() => Ok(68),
+        '*' if base.is_85() => Ok(69),
+        '+' if base.is_85() => Ok(70),
+        '-' if base.is_85() => Ok(71),
+        ';' if base.is_85() => Ok(72),
+        '<' if base.is_85() => Ok(73),
+        '=' if base.is_85() => Ok(74),
+        '>' if base.is_85() => Ok(75),
+        '?' if base.is_85() => Ok(76),
+        '@' if base.is_85() => Ok(77),
+        '^' if base.is_85() => Ok(78),
+        '_' if base.is_85() => Ok(79),
+        '`' if base.is_85() => Ok(80),
+        '{' if base.is_85() => Ok(81),
+        '|' if base.is_85() => Ok(82),
+        '}' if base.is_85() => Ok(83),
+        '~' if base.is_85() => Ok(84),
+
+        // NOTE base 85 ascii is done with this
+        '!'..='u' if base.is_85_ascii() => Ok(chr as u8 - b'!'),
+
+        // z85
+        // NOTE base 85 z is done with this
+        '0' if base.is_85_z() => Ok(0),
+        '1' if base.is_85_z() => Ok(1),
+        '2' if base.is_85_z() => Ok(2),
+        '3' if base.is_85_z() => Ok(3),
+        '4' if base.is_85_z() => Ok(4),
+        '5' if base.is_85_z() => Ok(5),
+        '6' if base.is_85_z() => Ok(6),
+        '7' if base.is_85_z() => Ok(7),
+        '8' if base.is_85_z() => Ok(8),
+        '9' if base.is_85_z() => Ok(9),
+        'a' if base.is_85_z() => Ok(10),
+        'b' if base.is_85_z() => Ok(11),
+        'c' if base.is_85_z() => Ok(12),
+        'd' if base.is_85_z() => Ok(13),
+        'e' if base.is_85_z() => Ok(14),
+        'f' if base.is_85_z() => Ok(15),
+        'g' if base.is_85_z() => Ok(16),
+        'h' if base.is_85_z() => Ok(17),
+        'i' if base.is_85_z() => Ok(18),
+        'j' if base.is_85_z() => Ok(19),
+        'k' if base.is_85_z() => Ok(20),
+        'l' if base.is_85_z() => Ok(21),
+        'm' if base.is_85_z() => Ok(22),
+        'n' if base.is_85_z() => Ok(23),
+        'o' if base.is_85_z() => Ok(24),
+        'p' if base.is_85_z() => Ok(25),
+        'q' if base.is_85_z() => Ok(26),
+        'r' if base.is_85_z() => Ok(27),
+        's' if base.is_85_z() => Ok(28),
+        't' if base.is_85_z() => Ok(29),
+        'u' if base.is_85_z() => Ok(30),
+        'v' if base.is_85_z() => Ok(31),
+        'w' if base.is_85_z() => Ok(32),
+        'x' if base.is_85_z() => Ok(33),
+        'y' if base.is_85_z() => Ok(34),
+        'z' if base.is_85_z() => Ok(35),
+        'A' if base.is_85_z() => Ok(36),
+        'B' if base.is_85_z() => Ok(37),
+        'C' if base.is_85_z() => Ok(38),
+        'D' if base.is_85_z() => Ok(39),
+        'E' if base.is_85_z() => Ok(40),
+        'F' if base.is_85_z() => Ok(41),
+        'G' if base.is_85_z() => Ok(42),
+        'H' if base.is_85_z() => Ok(43),
+        'I' if base.is_85_z() => Ok(44),
+        'J' if base.is_85_z() => Ok(45),
+        'K' if base.is_85_z() => Ok(46),
+        'L' if base.is_85_z() => Ok(47),
+        'M' if base.is_85_z() => Ok(48),
+        'N' if base.is_85_z() => Ok(49),
+        'O' if base.is_85_z() => Ok(50),
+        'P' if base.is_85_z() => Ok(51),
+        'Q' if base.is_85_z() => Ok(52),
+        'R' if base.is_85_z() => Ok(53),
+        'S' if base.is_85_z() => Ok(54),
+        'T' if base.is_85_z() => Ok(55),
+        'U' if base.is_85_z() => Ok(56),
+        'V' if base.is_85_z() => Ok(57),
+        'W' if base.is_85_z() => Ok(58),
+        'X' if base.is_85_z() => Ok(59),
+        'Y' if base.is_85_z() => Ok(60),
+        'Z' if base.is_85_z() => Ok(61),
+        '.' if base.is_85_z() => Ok(62),
+        '-' if base.is_85_z() => Ok(63),
+        ':' if base.is_85_z() => Ok(64),
+        '+' if base.is_85_z() => Ok(65),
+        '=' if base.is_85_z() => Ok(66),
+        '^' if base.is_85_z() => Ok(67),
+        '!' if base.is_85_z() => Ok(68),
+        '/' if base.is_85_z() => Ok(69),
+        '*' if base.is_85_z() => Ok(70),
+        '?' if base.is_85_z() => Ok(71),
+        '&' if base.is_85_z() => Ok(72),
+        '<' if base.is_85_z() => Ok(73),
+        '>' if base.is_85_z() => Ok(74),
+        '(' if base.is_85_z() => Ok(75),
+        ')' if base.is_85_z() => Ok(76),
+        '[' if base.is_85_z() => Ok(77),
+        ']' if base.is_85_z() => Ok(78),
+        '{' if base.is_85_z() => Ok(79),
+        '}' if base.is_85_z() => Ok(80),
+        '@' if base.is_85_z() => Ok(81),
+        '%' if base.is_85_z() => Ok(82),
+        '$' if base.is_85_z() => Ok(83),
+        '#' if base.is_85_z() => Ok(84),
+
+        // NOTE base 91 is done with this
+        '!' if base.is_91() => Ok(62),
+        '#' if base.is_91() => Ok(63),
+        '$' if base.is_91() => Ok(64),
+        '%' if base.is_91() => Ok(65),
+        '&' if base.is_91() => Ok(66),
+        '(' if base.is_91() => Ok(67),
+        ')' if base.is_91() => Ok(68),
+        '*' if base.is_91() => Ok(69),
+        '+' if base.is_91() => Ok(70),
+        ',' if base.is_91() => Ok(71),
+        '.' if base.is_91() => Ok(72),
+        '/' if base.is_91() => Ok(73),
+        ':' if base.is_91() => Ok(74),
+        ';' if base.is_91() => Ok(75),
+        '<' if base.is_91() => Ok(76),
+        '=' if base.is_91() => Ok(77),
+        '>' if base.is_91() => Ok(78),
+        '?' if base.is_91() => Ok(79),
+        '@' if base.is_91() => Ok(80),
+        '[' if base.is_91() => Ok(81),
+        ']' if base.is_91() => Ok(82),
+        '^' if base.is_91() => Ok(83),
+        '_' if base.is_91() => Ok(84),
+        '`' if base.is_91() => Ok(85),
+        '{' if base.is_91() => Ok(86),
+        '|' if base.is_91() => Ok(87),
+        '}' if base.is_91() => Ok(88),
+        '~' if base.is_91() => Ok(89),
+        '"' if base.is_91() => Ok(90),
+
+        // NOTE base 64 bcrypt and crypt are done with this
+        '.' if base.is_64_bcrypt() | base.is_64_crypt() => Ok(0),
+        '/' if base.is_64_bcrypt() | base.is_64_crypt() => Ok(1),
+        'A'..='Z' if base.is_64_bcrypt() => Ok(chr as u8 - b'A' + 2),
+        'a'..='z' if base.is_64_bcrypt() => Ok(chr as u8 - b'a' + 28),
+        '0'..='9' if base.is_64_bcrypt() => Ok(chr as u8 - b'0' + 54),
+        '0'..='9' if base.is_64_crypt() => Ok(chr as u8 - b'0' + 2),
+        'A'..='Z' if base.is_64_crypt() => Ok(chr as u8 - b'A' + 12),
+        'a'..='z' if base.is_64_crypt() => Ok(chr as u8 - b'a' + 38),
+
         // _ => panic!("got impossile table char {} for base {:?}", chr, base),
-        ch => Err(DecodeError::UnrecognizedCharForBase { ch, base: *base }),
+        _ => Err(DecodeError::UnrecognizedCharForBase {
+            ch: chr,
+            base: *base,
+        }),
+    }
+}
+
+// keeps only the first `bits` bits of the given bytes
+// the trailing bits of the last kept byte that fall outside of the bit count are zeroed
+pub(crate) fn truncate_bits(bytes: &mut makura_alloc::Vec<u8>, bits: usize) {
+    bytes.truncate(bits.div_ceil(8));
+    if bits % 8 != 0 {
+        if let Some(last) = bytes.last_mut() {
+            *last &= 0xff << (8 - bits % 8);
+        }
     }
 }
 
+// writes a byte as an escape char followed by the byte in uppercase base 16,
+// e.g., '%' and b'/' make "%2F"; the text formats share this escape
+#[cfg(any(
+    feature = "encoded_word",
+    feature = "quoted_printable",
+    feature = "percent_encoding"
+))]
+pub(crate) fn hex_escape(escape: char, b: u8, encoded: &mut makura_alloc::String) {
+    encoded.push(escape);
+    encoded.push(char_from_idx(b >> 4, &BASE16));
+    encoded.push(char_from_idx(b & 15, &BASE16));
+}
+
+// reads back the byte of the 2 hex digits that follow the escape char at idx,
+// the digits can be lowercase; returns None if they are missing or not hex digits
+#[cfg(any(
+    feature = "encoded_word",
+    feature = "quoted_printable",
+    feature = "percent_encoding"
+))]
+pub(crate) fn hex_unescape(bytes: &[u8], idx: usize) -> Option<u8> {
+    let hex = |i: usize| {
+        bytes
+            .get(i)
+            .and_then(|b| idx_from_char(*b as char, &BASE16).ok())
+    };
+
+    Some(hex(idx + 1)? << 4 | hex(idx + 2)?)
+}
+
+// wipes a buffer that held bytes derived from the decoded value before it is dropped
+// this is a no-op unless the zeroize feature is enabled
+pub(crate) fn wipe(bytes: &mut makura_alloc::Vec<u8>) {
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(bytes);
+    #[cfg(not(feature = "zeroize"))]
+    let _ = bytes;
+}
+
 // pub(self) mod char_checks {
 //
 //     pub(crate) fn is_base64(chr: char) -> bool {
@@ -419,10 +1272,7 @@ pub(crate) fn idx_from_char(chr: char, base: &Base) -> Result<u8, DecodeError> {
 
 pub(crate) mod makura_alloc {
     extern crate alloc;
+    pub(crate) use alloc::format;
     pub(crate) use alloc::string::{FromUtf8Error, String};
     pub(crate) use alloc::vec::Vec;
 }
-
-pub(crate) mod makura_core {
-    pub(crate) use core::ops;
-}
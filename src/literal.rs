@@ -0,0 +1,247 @@
+#![cfg(any(feature = "base16", feature = "base64"))]
+//! compile time decoding of base 16 and base 64 literals, and compile time base 64 encoding
+//!
+//! the `hex!`, `base64!` and `include_base64!` macros are built on the const fns of this module,
+//! so a bad literal is a compile error instead of a runtime panic
+//!
+//! ```
+//! const KEY: [u8; 4] = makura::hex!("deadbeef");
+//! const NONCE: [u8; 3] = makura::base64!("AQID");
+//!
+//! assert_eq!(KEY, [0xde, 0xad, 0xbe, 0xef]);
+//! assert_eq!(NONCE, [1, 2, 3]);
+//! ```
+//!
+//! ```compile_fail
+//! // 'g' is not a hex digit
+//! const KEY: [u8; 4] = makura::hex!("deadbeeg");
+//! ```
+
+#[cfg(feature = "base64")]
+use crate::BASE64_TABLE;
+
+/// the length of the bytes that a hex literal decodes into
+///
+/// # Panic
+/// panics if the literal has an odd length
+#[cfg(all(feature = "base16", feature = "decoding"))]
+pub const fn hex_len(value: &str) -> usize {
+    assert!(value.len() % 2 == 0, "hex literal has an odd length");
+
+    value.len() / 2
+}
+
+#[cfg(all(feature = "base16", feature = "decoding"))]
+const fn hex_digit(ch: u8) -> u8 {
+    match ch {
+        b'0'..=b'9' => ch - b'0',
+        b'A'..=b'F' => ch - b'A' + 10,
+        b'a'..=b'f' => ch - b'a' + 10,
+        _ => panic!("hex literal contains a char that is not a hex digit"),
+    }
+}
+
+/// decodes a hex literal, both uppercase and lowercase digits are accepted
+///
+/// # Panic
+/// panics if N is not the `hex_len` of the literal,
+/// or if the literal contains a char that is not a hex digit
+#[cfg(all(feature = "base16", feature = "decoding"))]
+pub const fn hex<const N: usize>(value: &str) -> [u8; N] {
+    assert!(
+        hex_len(value) == N,
+        "hex literal doesn't decode into N bytes"
+    );
+
+    let value = value.as_bytes();
+    let mut bytes = [0u8; N];
+    let mut idx = 0;
+    while idx < N {
+        bytes[idx] = hex_digit(value[idx * 2]) << 4 | hex_digit(value[idx * 2 + 1]);
+        idx += 1;
+    }
+
+    bytes
+}
+
+// the length of the literal without its '=' padding
+#[cfg(all(feature = "base64", feature = "decoding"))]
+const fn unpadded_len(value: &[u8]) -> usize {
+    let mut len = value.len();
+    while len > 0 && value[len - 1] == b'=' {
+        len -= 1;
+    }
+
+    len
+}
+
+/// the length of the bytes that a base 64 literal decodes into,
+/// the literal can be padded or not
+///
+/// # Panic
+/// panics if the literal has a length that no base 64 value has
+#[cfg(all(feature = "base64", feature = "decoding"))]
+pub const fn base64_len(value: &str) -> usize {
+    let len = unpadded_len(value.as_bytes());
+    assert!(len % 4 != 1, "base64 literal has a bad length");
+    // a padded literal pads its final partial group, with 1 or 2 '=', up to 4 chars
+    assert!(
+        len == value.len() || (value.len() % 4 == 0 && value.len() - len < 3 && len % 4 != 0),
+        "base64 literal has bad padding"
+    );
+
+    len * 6 / 8
+}
+
+#[cfg(all(feature = "base64", feature = "decoding"))]
+const fn base64_value(ch: u8) -> u8 {
+    let mut idx = 0;
+    while idx < BASE64_TABLE.len() {
+        if BASE64_TABLE[idx] == ch {
+            return idx as u8;
+        }
+        idx += 1;
+    }
+
+    panic!("base64 literal contains a char that is not part of the base64 table")
+}
+
+/// decodes a base 64 literal
+///
+/// # Panic
+/// panics if N is not the `base64_len` of the literal,
+/// or if the literal contains a char that is not part of the base 64 table
+#[cfg(all(feature = "base64", feature = "decoding"))]
+pub const fn base64<const N: usize>(value: &str) -> [u8; N] {
+    assert!(
+        base64_len(value) == N,
+        "base64 literal doesn't decode into N bytes"
+    );
+
+    let value = value.as_bytes();
+    let len = unpadded_len(value);
+    let mut bytes = [0u8; N];
+    let mut queue = 0u32;
+    let mut bits = 0;
+    let (mut read, mut written) = (0, 0);
+    while read < len {
+        queue = queue << 6 | base64_value(value[read]) as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes[written] = (queue >> bits) as u8;
+            written += 1;
+        }
+        read += 1;
+    }
+
+    bytes
+}
+
+/// the length of the padded base 64 encoding of len bytes
+#[cfg(all(feature = "base64", feature = "encoding"))]
+pub const fn base64_encoded_len(len: usize) -> usize {
+    len.div_ceil(3) * 4
+}
+
+// the missing bytes of a final partial chunk are zero filled
+#[cfg(all(feature = "base64", feature = "encoding"))]
+const fn byte_at(value: &[u8], idx: usize) -> u8 {
+    if idx < value.len() { value[idx] } else { 0 }
+}
+
+/// encodes the value into padded base 64 chars
+///
+/// # Panic
+/// panics if N is not the `base64_encoded_len` of the value
+#[cfg(all(feature = "base64", feature = "encoding"))]
+pub const fn base64_encode<const N: usize>(value: &[u8]) -> [u8; N] {
+    assert!(
+        base64_encoded_len(value.len()) == N,
+        "base64 encoding doesn't fit into N chars"
+    );
+
+    let mut encoded = [b'='; N];
+    let mut idx = 0;
+    while idx < value.len() {
+        let chunk = (byte_at(value, idx) as u32) << 16
+            | (byte_at(value, idx + 1) as u32) << 8
+            | byte_at(value, idx + 2) as u32;
+        let chars = if value.len() - idx < 3 {
+            value.len() - idx + 1
+        } else {
+            4
+        };
+
+        let mut ch = 0;
+        while ch < chars {
+            encoded[idx / 3 * 4 + ch] = BASE64_TABLE[(chunk >> (18 - ch * 6)) as usize & 63];
+            ch += 1;
+        }
+        idx += 3;
+    }
+
+    encoded
+}
+
+/// decodes a hex literal into a `[u8; N]` at compile time
+///
+/// a bad literal is a compile error
+///
+/// ```
+/// assert_eq!(makura::hex!("CAFE"), [0xca, 0xfe]);
+/// ```
+#[cfg(all(feature = "base16", feature = "decoding"))]
+#[macro_export]
+macro_rules! hex {
+    ($value:expr) => {{
+        const VALUE: &str = $value;
+        const BYTES: [u8; $crate::literal::hex_len(VALUE)] = $crate::literal::hex(VALUE);
+
+        BYTES
+    }};
+}
+
+/// decodes a base 64 literal into a `[u8; N]` at compile time,
+/// the literal can be padded or not
+///
+/// a bad literal is a compile error
+///
+/// ```
+/// assert_eq!(makura::base64!("Zm9vYg=="), *b"foob");
+/// ```
+///
+/// ```compile_fail
+/// // a whole group of padding
+/// const BYTES: [u8; 3] = makura::base64!("Zm9v====");
+/// ```
+#[cfg(all(feature = "base64", feature = "decoding"))]
+#[macro_export]
+macro_rules! base64 {
+    ($value:expr) => {{
+        const VALUE: &str = $value;
+        const BYTES: [u8; $crate::literal::base64_len(VALUE)] = $crate::literal::base64(VALUE);
+
+        BYTES
+    }};
+}
+
+/// includes a file as its padded base 64 encoding, a `&'static str` built at compile time
+///
+/// the path is resolved the same way `include_bytes!` resolves it,
+/// i.e., relative to the file that invokes the macro
+#[cfg(all(feature = "base64", feature = "encoding"))]
+#[macro_export]
+macro_rules! include_base64 {
+    ($path:expr) => {{
+        const BYTES: &[u8] = ::core::include_bytes!($path);
+        const CHARS: [u8; $crate::literal::base64_encoded_len(BYTES.len())] =
+            $crate::literal::base64_encode(BYTES);
+        const ENCODED: &str = match ::core::str::from_utf8(&CHARS) {
+            Ok(encoded) => encoded,
+            Err(_) => ::core::panic!("base64 chars are ascii"),
+        };
+
+        ENCODED
+    }};
+}
@@ -0,0 +1,105 @@
+#![cfg(feature = "multibase")]
+//! [multibase](https://github.com/multiformats/multibase) self describing encoded values
+//!
+//! the encoded payload is prefixed with a single char that tells which base it is encoded with,
+//! e.g., "f" for lowercase base 16 or "z" for base58btc,
+//! so unlike `Decoder::deduce_encoding`, decoding a prefixed value is never ambiguous
+use crate::makura_alloc::{String, Vec};
+
+use crate::{BASE16, BASE32, BASE32HEX, BASE32Z, BASE36, BASE45, BASE58, BASE64, BASE64URL};
+use crate::{Base, DecodeError, Decoder, Encoder};
+
+/// errors that can occur while encoding or decoding a multibase value
+#[derive(Debug)]
+pub enum MultibaseError {
+    /// the value is empty, it doesn't even have a prefix
+    MissingPrefix,
+    /// the prefix char is not one of the multibase prefixes that makura implements
+    UnknownPrefix(char),
+    /// the base has no multibase prefix
+    UnsupportedBase(Base),
+    /// the payload that comes after the prefix failed to decode
+    DecodeError(DecodeError),
+}
+
+// the canonical prefix of each base, this is the one that is used when encoding
+// base 16, 32, 32 hex and 36 are lowercase, and base 32 and 64 are unpadded
+fn prefix(base: &Base) -> Option<char> {
+    Some(match *base {
+        BASE16 => 'f',
+        BASE32 => 'b',
+        BASE32HEX => 'v',
+        BASE32Z => 'h',
+        BASE36 => 'k',
+        BASE45 => 'R',
+        BASE58 => 'z',
+        BASE64 => 'm',
+        BASE64URL => 'u',
+        _ => return None,
+    })
+}
+
+// all the prefixes that are decoded, including the uppercase and padded variants
+// returns the prefix's base and whether its payload is padded
+fn from_prefix(ch: char) -> Option<(Base, bool)> {
+    Some(match ch {
+        'f' | 'F' => (BASE16, false),
+        'b' | 'B' => (BASE32, false),
+        'c' | 'C' => (BASE32, true),
+        'v' | 'V' => (BASE32HEX, false),
+        't' | 'T' => (BASE32HEX, true),
+        'h' => (BASE32Z, false),
+        'k' | 'K' => (BASE36, false),
+        'R' => (BASE45, false),
+        'z' => (BASE58, false),
+        'm' => (BASE64, false),
+        'M' => (BASE64, true),
+        'u' => (BASE64URL, false),
+        'U' => (BASE64URL, true),
+        _ => return None,
+    })
+}
+
+/// encodes the value bytes with the given base and prefixes the result with the base's
+/// canonical multibase prefix
+///
+/// # Error
+/// returns an Err if the base has no multibase prefix, i.e.,
+/// base 62, any of the base 85 variants or base 91
+#[cfg(feature = "encoding")]
+pub fn encode(base: Base, value: impl AsRef<[u8]>) -> Result<String, MultibaseError> {
+    let prefix = prefix(&base).ok_or(MultibaseError::UnsupportedBase(base))?;
+    let encoded = Encoder::new(base).lowercase().encode_bytes(value);
+
+    let mut prefixed = String::with_capacity(encoded.len() + 1);
+    prefixed.push(prefix);
+    prefixed.push_str(encoded.trim_end_matches(crate::PAD));
+
+    Ok(prefixed)
+}
+
+/// reads the prefix of the multibase value and decodes the rest of it with the prefix's base
+///
+/// returns the base that the value was encoded with along with the decoded bytes
+///
+/// # Error
+/// returns an Err if the value has no prefix, if the prefix is unknown,
+/// or if the payload fails to decode with the prefix's base
+#[cfg(feature = "decoding")]
+pub fn decode(value: &str) -> Result<(Base, Vec<u8>), MultibaseError> {
+    let mut chars = value.chars();
+    let ch = chars.next().ok_or(MultibaseError::MissingPrefix)?;
+    let (base, padded) = from_prefix(ch).ok_or(MultibaseError::UnknownPrefix(ch))?;
+    let payload = chars.as_str();
+
+    // the decoders don't need the padding, but an unpadded prefix can't be followed by any
+    if !padded && payload.contains(crate::PAD) {
+        return Err(MultibaseError::DecodeError(
+            DecodeError::BaseEncodingHasNoPaddingChars(base),
+        ));
+    }
+
+    Decoder::decode_to_bytes(payload, base)
+        .map(|bytes| (base, bytes))
+        .map_err(MultibaseError::DecodeError)
+}
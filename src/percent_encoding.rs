@@ -0,0 +1,158 @@
+#![cfg(feature = "percent_encoding")]
+//! [RFC 3986](https://datatracker.ietf.org/doc/html/rfc3986#section-2.1) percent-encoding
+//!
+//! every byte that can't appear as it is in a url component is written as '%'
+//! followed by the byte in uppercase base16,
+//! e.g., "a b/c" -> "a%20b%2Fc" when encoded as a path segment
+//!
+//! which ascii chars are kept as they are depends on the url component,
+//! see the `PercentSet` constants; non ascii bytes are always encoded
+use crate::makura_alloc::{String, Vec};
+
+/// the ascii chars that are kept as they are by the encoder,
+/// all other ascii chars and all non ascii bytes are percent encoded
+///
+/// custom sets are built from one of the constants, e.g.,
+/// `PercentSet::UNRESERVED.keep(b'/')` for a whole path
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PercentSet {
+    // bit n is set when ascii char n is kept as it is
+    literal: u128,
+    // application/x-www-form-urlencoded writes spaces as '+'
+    space_as_plus: bool,
+}
+
+impl PercentSet {
+    /// only the rfc 3986 unreserved chars are kept; A-Z, a-z, 0-9, '-', '.', '_' and '~'
+    /// this is the safe set for any url component
+    pub const UNRESERVED: Self = Self::none().keep_all(b"-._~").keep_alnum();
+
+    /// a path segment (pchar); the unreserved chars, the sub-delims
+    /// "!$&'()*+,;=" and ':' '@' are kept, '/', '?' and '#' are encoded
+    pub const PATH_SEGMENT: Self = Self::UNRESERVED.keep_all(b"!$&'()*+,;=:@");
+
+    /// a query parameter name or value; same as a path segment plus '/' and '?',
+    /// except that the '&', '=', '+' and ';' query delimiters are encoded
+    pub const QUERY: Self = Self::UNRESERVED.keep_all(b"!$'()*,:@/?");
+
+    /// the user name or the password of the userinfo; the unreserved chars and the sub-delims
+    /// are kept, the ':' that separates the user name from the password is encoded
+    pub const USERINFO: Self = Self::UNRESERVED.keep_all(b"!$&'()*+,;=");
+
+    /// `application/x-www-form-urlencoded`, the html form submission encoding;
+    /// A-Z, a-z, 0-9, '*', '-', '.' and '_' are kept and spaces are written as '+'
+    pub const FORM: Self = Self {
+        space_as_plus: true,
+        ..Self::none().keep_all(b"*-._").keep_alnum()
+    };
+
+    // a set that encodes every byte
+    const fn none() -> Self {
+        Self {
+            literal: 0,
+            space_as_plus: false,
+        }
+    }
+
+    const fn keep_alnum(self) -> Self {
+        let mut set = self;
+        let mut ch = 0;
+        while ch < 128 {
+            if (ch as u8).is_ascii_alphanumeric() {
+                set = set.keep(ch as u8);
+            }
+            ch += 1;
+        }
+
+        set
+    }
+
+    const fn keep_all(self, chars: &[u8]) -> Self {
+        let mut set = self;
+        let mut idx = 0;
+        while idx < chars.len() {
+            set = set.keep(chars[idx]);
+            idx += 1;
+        }
+
+        set
+    }
+
+    /// keeps the given ascii char as it is
+    ///
+    /// # Panic
+    /// panics if ch is not ascii, non ascii bytes are always encoded
+    pub const fn keep(mut self, ch: u8) -> Self {
+        assert!(ch < 128, "only ascii chars can be kept as they are");
+        self.literal |= 1 << ch;
+
+        self
+    }
+
+    /// percent encodes the given ascii char
+    pub const fn escape(mut self, ch: u8) -> Self {
+        if ch < 128 {
+            self.literal &= !(1 << ch);
+        }
+
+        self
+    }
+
+    /// returns whether the byte is kept as it is
+    pub const fn is_kept(&self, b: u8) -> bool {
+        b < 128 && self.literal & (1 << b) != 0
+    }
+}
+
+/// errors that can occur while decoding a percent encoded value
+#[derive(Debug, PartialEq)]
+pub enum PercentError {
+    /// a '%' is not followed by 2 hex digits
+    /// .0 corresponds to the position of the '%' in the encoded value
+    BadEscape(usize),
+}
+
+/// percent encodes the value bytes that the set doesn't keep
+#[cfg(feature = "encoding")]
+pub fn encode(value: impl AsRef<[u8]>, set: PercentSet) -> String {
+    let value = value.as_ref();
+    let mut encoded = String::with_capacity(value.len());
+
+    value.iter().for_each(|b| match *b {
+        b' ' if set.space_as_plus => encoded.push('+'),
+        b if set.is_kept(b) => encoded.push(b as char),
+        b => crate::hex_escape('%', b, &mut encoded),
+    });
+
+    encoded
+}
+
+/// decodes the %XX escapes of the value, all other chars are kept as they are,
+/// except for '+' which is decoded into a space for the `FORM` set
+///
+/// the escapes' hex digits can be lowercase
+///
+/// # Error
+/// returns an Err if a '%' is not followed by 2 hex digits
+#[cfg(feature = "decoding")]
+pub fn decode(value: &str, set: PercentSet) -> Result<Vec<u8>, PercentError> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+
+    let mut idx = 0;
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'%' => {
+                let b = crate::hex_unescape(bytes, idx).ok_or(PercentError::BadEscape(idx))?;
+                decoded.push(b);
+                idx += 3;
+                continue;
+            }
+            b'+' if set.space_as_plus => decoded.push(b' '),
+            b => decoded.push(b),
+        }
+        idx += 1;
+    }
+
+    Ok(decoded)
+}
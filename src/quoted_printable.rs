@@ -0,0 +1,125 @@
+#![cfg(feature = "quoted_printable")]
+//! [RFC 2045](https://datatracker.ietf.org/doc/html/rfc2045#section-6.7) quoted-printable,
+//! the mime content transfer encoding for mostly ascii text
+//!
+//! printable ascii chars are kept as they are, all other bytes and '=' are written as
+//! "=XX" escapes, with XX being the byte in uppercase base16,
+//! and lines longer than 76 chars are split with "=\r\n" soft line breaks
+//!
+//! e.g., "café = coffee" -> "caf=C3=A9 =3D coffee"
+use crate::makura_alloc::{String, Vec};
+
+/// an encoded line can't be longer than this, the soft line break's '=' included
+const LINE_LEN: usize = 76;
+
+/// errors that can occur while decoding quoted-printable data
+#[derive(Debug, PartialEq)]
+pub enum QpError {
+    /// an '=' is neither followed by 2 hex digits nor by a line break
+    /// .0 corresponds to the position of the '=' in the encoded value
+    BadEscape(usize),
+}
+
+// encodes a line that holds no hard line breaks, adding soft line breaks where needed
+fn encode_line(line: &[u8], encoded: &mut String) {
+    let mut len = 0;
+    line.iter().enumerate().for_each(|(idx, b)| {
+        let last = idx + 1 == line.len();
+        // whitespace at the end of a line is escaped, since transports may strip it
+        let literal =
+            matches!(b, b'!'..=b'<' | b'>'..=b'~') || (!last && matches!(b, b' ' | b'\t'));
+        let width = if literal { 1 } else { 3 };
+
+        // the line's last char doesn't need room for the soft line break's '='
+        let room = if last { LINE_LEN } else { LINE_LEN - 1 };
+        if len + width > room {
+            encoded.push_str("=\r\n");
+            len = 0;
+        }
+
+        if literal {
+            encoded.push(*b as char);
+        } else {
+            crate::hex_escape('=', *b, encoded);
+        }
+        len += width;
+    });
+}
+
+/// encodes text, the "\n" and "\r\n" line breaks of the value are kept as "\r\n" hard line breaks
+///
+/// use `encode_binary` for data whose line break bytes have to survive the round trip as they are
+#[cfg(feature = "encoding")]
+pub fn encode(value: impl AsRef<[u8]>) -> String {
+    let value = value.as_ref();
+    let mut encoded = String::with_capacity(value.len());
+
+    let mut lines = value.split(|b| *b == b'\n').peekable();
+    while let Some(line) = lines.next() {
+        if lines.peek().is_none() {
+            encode_line(line, &mut encoded);
+            break;
+        }
+
+        encode_line(line.strip_suffix(b"\r").unwrap_or(line), &mut encoded);
+        encoded.push_str("\r\n");
+    }
+
+    encoded
+}
+
+/// encodes binary data, the '\r' and '\n' bytes are escaped like any other non printable byte
+/// so the encoded value only holds soft line breaks
+#[cfg(feature = "encoding")]
+pub fn encode_binary(value: impl AsRef<[u8]>) -> String {
+    let value = value.as_ref();
+    let mut encoded = String::with_capacity(value.len());
+    encode_line(value, &mut encoded);
+
+    encoded
+}
+
+/// decodes quoted-printable data
+///
+/// both "\r\n" and "\n" line breaks are accepted and hard line breaks are kept as they are,
+/// the whitespace that transports add at the end of lines is dropped
+/// and the escapes' hex digits can be lowercase
+///
+/// # Error
+/// returns an Err if an '=' is neither followed by 2 hex digits nor by a line break
+#[cfg(feature = "decoding")]
+pub fn decode(value: &str) -> Result<Vec<u8>, QpError> {
+    let mut decoded = Vec::with_capacity(value.len());
+
+    let mut offset = 0;
+    for line in value.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\r', '\n']);
+        let line_break = &line[content.len()..];
+        let content = content.trim_end_matches([' ', '\t']);
+
+        // a soft line break joins the line with the next one
+        let (content, line_break) = match content.strip_suffix('=') {
+            Some(content) => (content, ""),
+            None => (content, line_break),
+        };
+
+        let bytes = content.as_bytes();
+        let mut idx = 0;
+        while idx < bytes.len() {
+            if bytes[idx] != b'=' {
+                decoded.push(bytes[idx]);
+                idx += 1;
+                continue;
+            }
+
+            let b = crate::hex_unescape(bytes, idx).ok_or(QpError::BadEscape(offset + idx))?;
+            decoded.push(b);
+            idx += 3;
+        }
+        decoded.extend(line_break.bytes());
+
+        offset += line.len();
+    }
+
+    Ok(decoded)
+}
@@ -0,0 +1,154 @@
+#![cfg(feature = "simd")]
+//! simd kernels for base 64, base 64 url and base 16
+//!
+//! the kernels only encode or decode the leading whole blocks of a value,
+//! the rest of it, which holds any padding, is left to the scalar path,
+//! so the output is always the same as the scalar path's output
+//!
+//! the cpu features are detected at runtime; avx2 then sse4.1 on x86_64 and neon on aarch64.
+//! when none of them is available nothing is read and the whole value takes the scalar path
+use crate::makura_alloc::{String, Vec};
+
+use crate::{BASE16, BASE64, BASE64URL, Base, char_from_idx};
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64;
+#[cfg(target_arch = "x86_64")]
+mod x86_64;
+
+// the chars of base 64 table values 62 and 63, the only values that differ between
+// base 64 and base 64 url
+fn chars_62_63(base: &Base) -> (u8, u8) {
+    (char_from_idx(62, base) as u8, char_from_idx(63, base) as u8)
+}
+
+/// encodes the leading whole blocks of the value at the end of the output
+///
+/// returns the count of bytes that were read, which is 0 if the base has no simd kernel
+/// or if the value is too short for a single block
+pub(crate) fn encode(value: &[u8], base: &Base, encoded: &mut String) -> usize {
+    // SAFETY: the kernels only write chars from the ascii encoding tables
+    let encoded = unsafe { encoded.as_mut_vec() };
+    match *base {
+        BASE16 => base16_encode(value, encoded),
+        BASE64 | BASE64URL => base64_encode(value, base, encoded),
+        _ => 0,
+    }
+}
+
+/// decodes the leading whole blocks of the value at the end of the output
+///
+/// returns the count of chars that were read,
+/// or None if the base has no simd kernel, if the value is too short for a single block,
+/// or if a char that doesn't belong to the base's table was found;
+/// in which case the output is left as it was
+/// and the whole value has to take the scalar path, which reports the error
+pub(crate) fn decode(value: &str, base: &Base, decoded: &mut Vec<u8>) -> Option<usize> {
+    let value = value.as_bytes();
+    let start = decoded.len();
+    let read = match *base {
+        BASE16 => base16_decode(value, decoded),
+        // the last group is left to the scalar path since it holds the padding
+        BASE64 | BASE64URL if value.len() % 4 == 0 && !value.is_empty() => {
+            base64_decode(&value[..value.len() - 4], base, decoded)
+        }
+        _ => Some(0),
+    };
+    if read.is_none_or(|read| read == 0) {
+        decoded.truncate(start);
+        return None;
+    }
+
+    read
+}
+
+#[allow(unused_variables, unused_mut)]
+fn base16_encode(value: &[u8], encoded: &mut Vec<u8>) -> usize {
+    let mut read = 0;
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::is_x86_feature_detected!("avx2") {
+            read += unsafe { x86_64::base16_encode_avx2(value, encoded) };
+        }
+        if std::is_x86_feature_detected!("sse4.1") {
+            read += unsafe { x86_64::base16_encode_sse(&value[read..], encoded) };
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        read += unsafe { aarch64::base16_encode(value, encoded) };
+    }
+
+    read
+}
+
+#[allow(unused_variables, unused_mut)]
+fn base16_decode(value: &[u8], decoded: &mut Vec<u8>) -> Option<usize> {
+    let mut read = 0;
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::is_x86_feature_detected!("avx2") {
+            read += unsafe { x86_64::base16_decode_avx2(value, decoded)? };
+        }
+        if std::is_x86_feature_detected!("sse4.1") {
+            read += unsafe { x86_64::base16_decode_sse(&value[read..], decoded)? };
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        read += unsafe { aarch64::base16_decode(value, decoded)? };
+    }
+
+    Some(read)
+}
+
+#[allow(unused_variables, unused_mut)]
+fn base64_encode(value: &[u8], base: &Base, encoded: &mut Vec<u8>) -> usize {
+    let (c62, c63) = chars_62_63(base);
+    let mut read = 0;
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::is_x86_feature_detected!("avx2") {
+            read += unsafe { x86_64::base64_encode_avx2(value, c62, c63, encoded) };
+        }
+        if std::is_x86_feature_detected!("sse4.1") {
+            read += unsafe { x86_64::base64_encode_sse(&value[read..], c62, c63, encoded) };
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        let table: [u8; 64] = core::array::from_fn(|idx| char_from_idx(idx as u8, base) as u8);
+        read += unsafe { aarch64::base64_encode(value, &table, encoded) };
+    }
+
+    read
+}
+
+#[allow(unused_variables, unused_mut)]
+fn base64_decode(value: &[u8], base: &Base, decoded: &mut Vec<u8>) -> Option<usize> {
+    let (c62, c63) = chars_62_63(base);
+    let mut read = 0;
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::is_x86_feature_detected!("avx2") {
+            read += unsafe { x86_64::base64_decode_avx2(value, c62, c63, decoded)? };
+        }
+        if std::is_x86_feature_detected!("sse4.1") {
+            read += unsafe { x86_64::base64_decode_sse(&value[read..], c62, c63, decoded)? };
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        read += unsafe { aarch64::base64_decode(value, c62, c63, decoded)? };
+    }
+
+    Some(read)
+}
@@ -0,0 +1,178 @@
+// neon kernels
+//
+// the interleaved loads and stores of neon split the values into their hex digit pairs
+// and base 64 groups, so the kernels work on one byte per lane with plain shifts
+//
+// NOTE the intrinsics are only safe to call from target_feature fns since rustc 1.87,
+// the msrv still needs the unsafe blocks
+#![allow(unused_unsafe)]
+use core::arch::aarch64::*;
+
+use crate::makura_alloc::Vec;
+
+const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+// 16 bytes -> 32 chars
+#[target_feature(enable = "neon")]
+pub(super) unsafe fn base16_encode(value: &[u8], encoded: &mut Vec<u8>) -> usize {
+    unsafe {
+        let table = vld1q_u8(HEX.as_ptr());
+        let nibble = vdupq_n_u8(0x0f);
+
+        let mut read = 0;
+        while read + 16 <= value.len() {
+            let bytes = vld1q_u8(value.as_ptr().add(read));
+            let chars = uint8x16x2_t(
+                vqtbl1q_u8(table, vshrq_n_u8(bytes, 4)),
+                vqtbl1q_u8(table, vandq_u8(bytes, nibble)),
+            );
+
+            let mut buf = [0u8; 32];
+            vst2q_u8(buf.as_mut_ptr(), chars);
+            encoded.extend_from_slice(&buf);
+            read += 16;
+        }
+
+        read
+    }
+}
+
+// hex chars -> nibbles, along with a mask of the valid chars
+// lowercase chars are valid, same as the scalar path
+#[inline]
+#[target_feature(enable = "neon")]
+unsafe fn hex_nibbles(chars: uint8x16_t) -> (uint8x16_t, uint8x16_t) {
+    unsafe {
+        let digit = vsubq_u8(chars, vdupq_n_u8(b'0'));
+        let is_digit = vcleq_u8(digit, vdupq_n_u8(9));
+        let alpha = vsubq_u8(vorrq_u8(chars, vdupq_n_u8(0x20)), vdupq_n_u8(b'a'));
+        let is_alpha = vcleq_u8(alpha, vdupq_n_u8(5));
+
+        let nibbles = vorrq_u8(
+            vandq_u8(digit, is_digit),
+            vandq_u8(vaddq_u8(alpha, vdupq_n_u8(10)), is_alpha),
+        );
+
+        (nibbles, vorrq_u8(is_digit, is_alpha))
+    }
+}
+
+// 32 chars -> 16 bytes
+#[target_feature(enable = "neon")]
+pub(super) unsafe fn base16_decode(value: &[u8], decoded: &mut Vec<u8>) -> Option<usize> {
+    unsafe {
+        let mut read = 0;
+        while read + 32 <= value.len() {
+            let chars = vld2q_u8(value.as_ptr().add(read));
+            let (hi, hi_valid) = hex_nibbles(chars.0);
+            let (lo, lo_valid) = hex_nibbles(chars.1);
+            if vminvq_u8(vandq_u8(hi_valid, lo_valid)) != 0xff {
+                return None;
+            }
+
+            let mut buf = [0u8; 16];
+            vst1q_u8(buf.as_mut_ptr(), vorrq_u8(vshlq_n_u8(hi, 4), lo));
+            decoded.extend_from_slice(&buf);
+            read += 32;
+        }
+
+        Some(read)
+    }
+}
+
+// 48 bytes -> 64 chars
+#[target_feature(enable = "neon")]
+pub(super) unsafe fn base64_encode(value: &[u8], table: &[u8; 64], encoded: &mut Vec<u8>) -> usize {
+    unsafe {
+        let table = vld1q_u8_x4(table.as_ptr());
+        let low_6 = vdupq_n_u8(0x3f);
+
+        let mut read = 0;
+        while read + 48 <= value.len() {
+            let uint8x16x3_t(a, b, c) = vld3q_u8(value.as_ptr().add(read));
+            let indices = [
+                vshrq_n_u8(a, 2),
+                vandq_u8(vorrq_u8(vshlq_n_u8(a, 4), vshrq_n_u8(b, 4)), low_6),
+                vandq_u8(vorrq_u8(vshlq_n_u8(b, 2), vshrq_n_u8(c, 6)), low_6),
+                vandq_u8(c, low_6),
+            ];
+            let chars = uint8x16x4_t(
+                vqtbl4q_u8(table, indices[0]),
+                vqtbl4q_u8(table, indices[1]),
+                vqtbl4q_u8(table, indices[2]),
+                vqtbl4q_u8(table, indices[3]),
+            );
+
+            let mut buf = [0u8; 64];
+            vst4q_u8(buf.as_mut_ptr(), chars);
+            encoded.extend_from_slice(&buf);
+            read += 48;
+        }
+
+        read
+    }
+}
+
+// chars -> table indices, along with a mask of the valid chars
+#[inline]
+#[target_feature(enable = "neon")]
+unsafe fn base64_values(chars: uint8x16_t, c62: u8, c63: u8) -> (uint8x16_t, uint8x16_t) {
+    unsafe {
+        let range = |first: u8, len: u8, value: u8| {
+            let offset = vsubq_u8(chars, vdupq_n_u8(first));
+            let is_in = vcltq_u8(offset, vdupq_n_u8(len));
+
+            (vandq_u8(vaddq_u8(offset, vdupq_n_u8(value)), is_in), is_in)
+        };
+        let (upper, is_upper) = range(b'A', 26, 0);
+        let (lower, is_lower) = range(b'a', 26, 26);
+        let (digit, is_digit) = range(b'0', 10, 52);
+        let (v62, is_62) = range(c62, 1, 62);
+        let (v63, is_63) = range(c63, 1, 63);
+
+        let values = vorrq_u8(vorrq_u8(vorrq_u8(upper, lower), vorrq_u8(digit, v62)), v63);
+        let valid = vorrq_u8(
+            vorrq_u8(vorrq_u8(is_upper, is_lower), vorrq_u8(is_digit, is_62)),
+            is_63,
+        );
+
+        (values, valid)
+    }
+}
+
+// 64 chars -> 48 bytes
+#[target_feature(enable = "neon")]
+pub(super) unsafe fn base64_decode(
+    value: &[u8],
+    c62: u8,
+    c63: u8,
+    decoded: &mut Vec<u8>,
+) -> Option<usize> {
+    unsafe {
+        let mut read = 0;
+        while read + 64 <= value.len() {
+            let uint8x16x4_t(a, b, c, d) = vld4q_u8(value.as_ptr().add(read));
+            let (a, a_valid) = base64_values(a, c62, c63);
+            let (b, b_valid) = base64_values(b, c62, c63);
+            let (c, c_valid) = base64_values(c, c62, c63);
+            let (d, d_valid) = base64_values(d, c62, c63);
+            let valid = vandq_u8(vandq_u8(a_valid, b_valid), vandq_u8(c_valid, d_valid));
+            if vminvq_u8(valid) != 0xff {
+                return None;
+            }
+
+            let bytes = uint8x16x3_t(
+                vorrq_u8(vshlq_n_u8(a, 2), vshrq_n_u8(b, 4)),
+                vorrq_u8(vshlq_n_u8(b, 4), vshrq_n_u8(c, 2)),
+                vorrq_u8(vshlq_n_u8(c, 6), d),
+            );
+
+            let mut buf = [0u8; 48];
+            vst3q_u8(buf.as_mut_ptr(), bytes);
+            decoded.extend_from_slice(&buf);
+            read += 64;
+        }
+
+        Some(read)
+    }
+}
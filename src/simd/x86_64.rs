@@ -0,0 +1,461 @@
+// sse4.1 and avx2 kernels
+//
+// the base 64 encoding kernels follow Muła and Lemire's "Faster Base64 Encoding and Decoding
+// Using AVX2 Instructions", the decoding kernels validate and translate the chars
+// with range comparisons then regroup the 6 bits values the same way the paper does
+//
+// NOTE the intrinsics are only safe to call from target_feature fns since rustc 1.87,
+// the msrv still needs the unsafe blocks
+#![allow(unused_unsafe)]
+use core::arch::x86_64::*;
+
+use crate::makura_alloc::Vec;
+
+const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+// 16 bytes -> 32 chars
+#[target_feature(enable = "sse4.1")]
+pub(super) unsafe fn base16_encode_sse(value: &[u8], encoded: &mut Vec<u8>) -> usize {
+    unsafe {
+        let table = _mm_loadu_si128(HEX.as_ptr().cast());
+        let nibble = _mm_set1_epi8(0x0f);
+
+        let mut read = 0;
+        while read + 16 <= value.len() {
+            let bytes = _mm_loadu_si128(value.as_ptr().add(read).cast());
+            let hi = _mm_shuffle_epi8(table, _mm_and_si128(_mm_srli_epi16(bytes, 4), nibble));
+            let lo = _mm_shuffle_epi8(table, _mm_and_si128(bytes, nibble));
+
+            let mut chars = [0u8; 32];
+            _mm_storeu_si128(chars.as_mut_ptr().cast(), _mm_unpacklo_epi8(hi, lo));
+            _mm_storeu_si128(chars.as_mut_ptr().add(16).cast(), _mm_unpackhi_epi8(hi, lo));
+            encoded.extend_from_slice(&chars);
+            read += 16;
+        }
+
+        read
+    }
+}
+
+// 32 bytes -> 64 chars
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn base16_encode_avx2(value: &[u8], encoded: &mut Vec<u8>) -> usize {
+    unsafe {
+        let table = _mm256_broadcastsi128_si256(_mm_loadu_si128(HEX.as_ptr().cast()));
+        let nibble = _mm256_set1_epi8(0x0f);
+
+        let mut read = 0;
+        while read + 32 <= value.len() {
+            let bytes = _mm256_loadu_si256(value.as_ptr().add(read).cast());
+            let hi =
+                _mm256_shuffle_epi8(table, _mm256_and_si256(_mm256_srli_epi16(bytes, 4), nibble));
+            let lo = _mm256_shuffle_epi8(table, _mm256_and_si256(bytes, nibble));
+            // the unpacking works inside of the 128 bits lanes, the lanes are put back in order
+            let first = _mm256_unpacklo_epi8(hi, lo);
+            let second = _mm256_unpackhi_epi8(hi, lo);
+
+            let mut chars = [0u8; 64];
+            _mm256_storeu_si256(
+                chars.as_mut_ptr().cast(),
+                _mm256_permute2x128_si256(first, second, 0x20),
+            );
+            _mm256_storeu_si256(
+                chars.as_mut_ptr().add(32).cast(),
+                _mm256_permute2x128_si256(first, second, 0x31),
+            );
+            encoded.extend_from_slice(&chars);
+            read += 32;
+        }
+
+        read
+    }
+}
+
+// hex chars -> nibbles, along with a mask of the valid chars
+// lowercase chars are valid, same as the scalar path
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn hex_nibbles_sse(chars: __m128i) -> (__m128i, __m128i) {
+    unsafe {
+        let digit = _mm_sub_epi8(chars, _mm_set1_epi8(b'0' as i8));
+        let is_digit = _mm_cmpeq_epi8(_mm_min_epu8(digit, _mm_set1_epi8(9)), digit);
+        let alpha = _mm_sub_epi8(
+            _mm_or_si128(chars, _mm_set1_epi8(0x20)),
+            _mm_set1_epi8(b'a' as i8),
+        );
+        let is_alpha = _mm_cmpeq_epi8(_mm_min_epu8(alpha, _mm_set1_epi8(5)), alpha);
+
+        let nibbles = _mm_or_si128(
+            _mm_and_si128(digit, is_digit),
+            _mm_and_si128(_mm_add_epi8(alpha, _mm_set1_epi8(10)), is_alpha),
+        );
+
+        (nibbles, _mm_or_si128(is_digit, is_alpha))
+    }
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn hex_nibbles_avx2(chars: __m256i) -> (__m256i, __m256i) {
+    unsafe {
+        let digit = _mm256_sub_epi8(chars, _mm256_set1_epi8(b'0' as i8));
+        let is_digit = _mm256_cmpeq_epi8(_mm256_min_epu8(digit, _mm256_set1_epi8(9)), digit);
+        let alpha = _mm256_sub_epi8(
+            _mm256_or_si256(chars, _mm256_set1_epi8(0x20)),
+            _mm256_set1_epi8(b'a' as i8),
+        );
+        let is_alpha = _mm256_cmpeq_epi8(_mm256_min_epu8(alpha, _mm256_set1_epi8(5)), alpha);
+
+        let nibbles = _mm256_or_si256(
+            _mm256_and_si256(digit, is_digit),
+            _mm256_and_si256(_mm256_add_epi8(alpha, _mm256_set1_epi8(10)), is_alpha),
+        );
+
+        (nibbles, _mm256_or_si256(is_digit, is_alpha))
+    }
+}
+
+// 32 chars -> 16 bytes
+#[target_feature(enable = "sse4.1")]
+pub(super) unsafe fn base16_decode_sse(value: &[u8], decoded: &mut Vec<u8>) -> Option<usize> {
+    unsafe {
+        // hi nibble * 16 + lo nibble
+        let merge = _mm_set1_epi16(0x0110);
+
+        let mut read = 0;
+        while read + 32 <= value.len() {
+            let (first, first_valid) =
+                hex_nibbles_sse(_mm_loadu_si128(value.as_ptr().add(read).cast()));
+            let (second, second_valid) =
+                hex_nibbles_sse(_mm_loadu_si128(value.as_ptr().add(read + 16).cast()));
+            if _mm_movemask_epi8(_mm_and_si128(first_valid, second_valid)) != 0xffff {
+                return None;
+            }
+
+            let bytes = _mm_packus_epi16(
+                _mm_maddubs_epi16(first, merge),
+                _mm_maddubs_epi16(second, merge),
+            );
+            let mut buf = [0u8; 16];
+            _mm_storeu_si128(buf.as_mut_ptr().cast(), bytes);
+            decoded.extend_from_slice(&buf);
+            read += 32;
+        }
+
+        Some(read)
+    }
+}
+
+// 64 chars -> 32 bytes
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn base16_decode_avx2(value: &[u8], decoded: &mut Vec<u8>) -> Option<usize> {
+    unsafe {
+        let merge = _mm256_set1_epi16(0x0110);
+
+        let mut read = 0;
+        while read + 64 <= value.len() {
+            let (first, first_valid) =
+                hex_nibbles_avx2(_mm256_loadu_si256(value.as_ptr().add(read).cast()));
+            let (second, second_valid) =
+                hex_nibbles_avx2(_mm256_loadu_si256(value.as_ptr().add(read + 32).cast()));
+            if _mm256_movemask_epi8(_mm256_and_si256(first_valid, second_valid)) != -1 {
+                return None;
+            }
+
+            // the packing works inside of the 128 bits lanes, the 64 bits quarters
+            // are put back in order
+            let bytes = _mm256_packus_epi16(
+                _mm256_maddubs_epi16(first, merge),
+                _mm256_maddubs_epi16(second, merge),
+            );
+            let bytes = _mm256_permute4x64_epi64(bytes, 0b11_01_10_00);
+            let mut buf = [0u8; 32];
+            _mm256_storeu_si256(buf.as_mut_ptr().cast(), bytes);
+            decoded.extend_from_slice(&buf);
+            read += 64;
+        }
+
+        Some(read)
+    }
+}
+
+// 12 bytes, spread over the 16 bytes of the register -> 16 table indices
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn base64_indices_sse(bytes: __m128i) -> __m128i {
+    unsafe {
+        let bytes = _mm_shuffle_epi8(
+            bytes,
+            _mm_set_epi8(10, 11, 9, 10, 7, 8, 6, 7, 4, 5, 3, 4, 1, 2, 0, 1),
+        );
+        let ac = _mm_mulhi_epu16(
+            _mm_and_si128(bytes, _mm_set1_epi32(0x0fc0fc00)),
+            _mm_set1_epi32(0x04000040),
+        );
+        let bd = _mm_mullo_epi16(
+            _mm_and_si128(bytes, _mm_set1_epi32(0x003f03f0)),
+            _mm_set1_epi32(0x01000010),
+        );
+
+        _mm_or_si128(ac, bd)
+    }
+}
+
+// the offsets that turn each range of the table indices into its chars,
+// the ranges are picked by base64_chars_*
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn base64_offsets(c62: u8, c63: u8) -> __m128i {
+    unsafe {
+        let digit = (b'0' as i8).wrapping_sub(52);
+        _mm_setr_epi8(
+            (b'a' as i8).wrapping_sub(26),
+            digit,
+            digit,
+            digit,
+            digit,
+            digit,
+            digit,
+            digit,
+            digit,
+            digit,
+            digit,
+            c62.wrapping_sub(62) as i8,
+            c63.wrapping_sub(63) as i8,
+            b'A' as i8,
+            0,
+            0,
+        )
+    }
+}
+
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn base64_chars_sse(indices: __m128i, offsets: __m128i) -> __m128i {
+    unsafe {
+        // 0 -> a..z, 1..=10 -> 0..9, 11 -> 62, 12 -> 63, 13 -> A..Z
+        let range = _mm_subs_epu8(indices, _mm_set1_epi8(51));
+        let upper = _mm_cmpgt_epi8(_mm_set1_epi8(26), indices);
+        let range = _mm_or_si128(range, _mm_and_si128(upper, _mm_set1_epi8(13)));
+
+        _mm_add_epi8(_mm_shuffle_epi8(offsets, range), indices)
+    }
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn base64_chars_avx2(indices: __m256i, offsets: __m256i) -> __m256i {
+    unsafe {
+        let range = _mm256_subs_epu8(indices, _mm256_set1_epi8(51));
+        let upper = _mm256_cmpgt_epi8(_mm256_set1_epi8(26), indices);
+        let range = _mm256_or_si256(range, _mm256_and_si256(upper, _mm256_set1_epi8(13)));
+
+        _mm256_add_epi8(_mm256_shuffle_epi8(offsets, range), indices)
+    }
+}
+
+// 12 bytes -> 16 chars, 16 bytes are loaded
+#[target_feature(enable = "sse4.1")]
+pub(super) unsafe fn base64_encode_sse(
+    value: &[u8],
+    c62: u8,
+    c63: u8,
+    encoded: &mut Vec<u8>,
+) -> usize {
+    unsafe {
+        let offsets = base64_offsets(c62, c63);
+
+        let mut read = 0;
+        while read + 16 <= value.len() {
+            let bytes = _mm_loadu_si128(value.as_ptr().add(read).cast());
+            let chars = base64_chars_sse(base64_indices_sse(bytes), offsets);
+
+            let mut buf = [0u8; 16];
+            _mm_storeu_si128(buf.as_mut_ptr().cast(), chars);
+            encoded.extend_from_slice(&buf);
+            read += 12;
+        }
+
+        read
+    }
+}
+
+// 24 bytes -> 32 chars, 12 bytes are loaded into each 128 bits lane
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn base64_encode_avx2(
+    value: &[u8],
+    c62: u8,
+    c63: u8,
+    encoded: &mut Vec<u8>,
+) -> usize {
+    unsafe {
+        let offsets = _mm256_broadcastsi128_si256(base64_offsets(c62, c63));
+        let spread = _mm256_broadcastsi128_si256(_mm_set_epi8(
+            10, 11, 9, 10, 7, 8, 6, 7, 4, 5, 3, 4, 1, 2, 0, 1,
+        ));
+
+        let mut read = 0;
+        while read + 28 <= value.len() {
+            let bytes = _mm256_set_m128i(
+                _mm_loadu_si128(value.as_ptr().add(read + 12).cast()),
+                _mm_loadu_si128(value.as_ptr().add(read).cast()),
+            );
+            let bytes = _mm256_shuffle_epi8(bytes, spread);
+            let ac = _mm256_mulhi_epu16(
+                _mm256_and_si256(bytes, _mm256_set1_epi32(0x0fc0fc00)),
+                _mm256_set1_epi32(0x04000040),
+            );
+            let bd = _mm256_mullo_epi16(
+                _mm256_and_si256(bytes, _mm256_set1_epi32(0x003f03f0)),
+                _mm256_set1_epi32(0x01000010),
+            );
+            let chars = base64_chars_avx2(_mm256_or_si256(ac, bd), offsets);
+
+            let mut buf = [0u8; 32];
+            _mm256_storeu_si256(buf.as_mut_ptr().cast(), chars);
+            encoded.extend_from_slice(&buf);
+            read += 24;
+        }
+
+        read
+    }
+}
+
+// chars -> table indices, along with a mask of the valid chars
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn base64_values_sse(chars: __m128i, c62: u8, c63: u8) -> (__m128i, __m128i) {
+    unsafe {
+        let range = |first: u8, len: i8, value: i8| {
+            let offset = _mm_sub_epi8(chars, _mm_set1_epi8(first as i8));
+            let is_in = _mm_cmpeq_epi8(_mm_min_epu8(offset, _mm_set1_epi8(len - 1)), offset);
+
+            (
+                _mm_and_si128(_mm_add_epi8(offset, _mm_set1_epi8(value)), is_in),
+                is_in,
+            )
+        };
+        let (upper, is_upper) = range(b'A', 26, 0);
+        let (lower, is_lower) = range(b'a', 26, 26);
+        let (digit, is_digit) = range(b'0', 10, 52);
+        let (v62, is_62) = range(c62, 1, 62);
+        let (v63, is_63) = range(c63, 1, 63);
+
+        let values = _mm_or_si128(
+            _mm_or_si128(_mm_or_si128(upper, lower), _mm_or_si128(digit, v62)),
+            v63,
+        );
+        let valid = _mm_or_si128(
+            _mm_or_si128(
+                _mm_or_si128(is_upper, is_lower),
+                _mm_or_si128(is_digit, is_62),
+            ),
+            is_63,
+        );
+
+        (values, valid)
+    }
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn base64_values_avx2(chars: __m256i, c62: u8, c63: u8) -> (__m256i, __m256i) {
+    unsafe {
+        let range = |first: u8, len: i8, value: i8| {
+            let offset = _mm256_sub_epi8(chars, _mm256_set1_epi8(first as i8));
+            let is_in =
+                _mm256_cmpeq_epi8(_mm256_min_epu8(offset, _mm256_set1_epi8(len - 1)), offset);
+
+            (
+                _mm256_and_si256(_mm256_add_epi8(offset, _mm256_set1_epi8(value)), is_in),
+                is_in,
+            )
+        };
+        let (upper, is_upper) = range(b'A', 26, 0);
+        let (lower, is_lower) = range(b'a', 26, 26);
+        let (digit, is_digit) = range(b'0', 10, 52);
+        let (v62, is_62) = range(c62, 1, 62);
+        let (v63, is_63) = range(c63, 1, 63);
+
+        let values = _mm256_or_si256(
+            _mm256_or_si256(_mm256_or_si256(upper, lower), _mm256_or_si256(digit, v62)),
+            v63,
+        );
+        let valid = _mm256_or_si256(
+            _mm256_or_si256(
+                _mm256_or_si256(is_upper, is_lower),
+                _mm256_or_si256(is_digit, is_62),
+            ),
+            is_63,
+        );
+
+        (values, valid)
+    }
+}
+
+// the byte order of the 24 bits that each 32 bits value holds after the regrouping
+const PACK: [i8; 16] = [2, 1, 0, 6, 5, 4, 10, 9, 8, 14, 13, 12, -1, -1, -1, -1];
+
+// 16 chars -> 12 bytes
+#[target_feature(enable = "sse4.1")]
+pub(super) unsafe fn base64_decode_sse(
+    value: &[u8],
+    c62: u8,
+    c63: u8,
+    decoded: &mut Vec<u8>,
+) -> Option<usize> {
+    unsafe {
+        let pack = _mm_loadu_si128(PACK.as_ptr().cast());
+
+        let mut read = 0;
+        while read + 16 <= value.len() {
+            let chars = _mm_loadu_si128(value.as_ptr().add(read).cast());
+            let (values, valid) = base64_values_sse(chars, c62, c63);
+            if _mm_movemask_epi8(valid) != 0xffff {
+                return None;
+            }
+
+            // 4 x 6 bits -> 2 x 12 bits -> 24 bits
+            let merged = _mm_maddubs_epi16(values, _mm_set1_epi32(0x01400140));
+            let merged = _mm_madd_epi16(merged, _mm_set1_epi32(0x00011000));
+            let mut buf = [0u8; 16];
+            _mm_storeu_si128(buf.as_mut_ptr().cast(), _mm_shuffle_epi8(merged, pack));
+            decoded.extend_from_slice(&buf[..12]);
+            read += 16;
+        }
+
+        Some(read)
+    }
+}
+
+// 32 chars -> 24 bytes, 12 bytes in each 128 bits lane
+#[target_feature(enable = "avx2")]
+pub(super) unsafe fn base64_decode_avx2(
+    value: &[u8],
+    c62: u8,
+    c63: u8,
+    decoded: &mut Vec<u8>,
+) -> Option<usize> {
+    unsafe {
+        let pack = _mm256_broadcastsi128_si256(_mm_loadu_si128(PACK.as_ptr().cast()));
+
+        let mut read = 0;
+        while read + 32 <= value.len() {
+            let chars = _mm256_loadu_si256(value.as_ptr().add(read).cast());
+            let (values, valid) = base64_values_avx2(chars, c62, c63);
+            if _mm256_movemask_epi8(valid) != -1 {
+                return None;
+            }
+
+            let merged = _mm256_maddubs_epi16(values, _mm256_set1_epi32(0x01400140));
+            let merged = _mm256_madd_epi16(merged, _mm256_set1_epi32(0x00011000));
+            let mut buf = [0u8; 32];
+            _mm256_storeu_si256(buf.as_mut_ptr().cast(), _mm256_shuffle_epi8(merged, pack));
+            decoded.extend_from_slice(&buf[..12]);
+            decoded.extend_from_slice(&buf[16..28]);
+            read += 32;
+        }
+
+        Some(read)
+    }
+}
@@ -0,0 +1,180 @@
+#![cfg(feature = "uuencode")]
+//! uuencoded and xxencoded files, including their begin/end framing
+//!
+//! ```text
+//! begin 644 cat.txt
+//! #0V%T
+//! `
+//! end
+//! ```
+//!
+//! every body line starts with a char that holds the count of bytes that the line encodes,
+//! at most 45, followed by the line's bytes in groups of 3 bytes -> 4 chars.
+//! a zero count line ends the body
+//!
+//! both flavors regroup the bytes the same way base64 does, they only differ in their tables
+use crate::makura_alloc::{String, Vec, format};
+
+/// the count of bytes that a full body line encodes
+const LINE_LEN: usize = 45;
+
+/// the xxencode table
+const XX: &[u8; 64] = b"+-0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// the table that is used to encode the file body
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Flavor {
+    /// the ascii range ' ' -> '_', except that zero is written as '`' instead of ' '
+    Uu,
+    /// '+', '-', then the numbers 0 -> 9 and the alphabet letters A -> Z then a -> z
+    Xx,
+}
+
+impl Flavor {
+    fn char_from_idx(&self, idx: u8) -> char {
+        match self {
+            Self::Uu if idx == 0 => '`',
+            Self::Uu => (idx + b' ') as char,
+            Self::Xx => XX[idx as usize] as char,
+        }
+    }
+
+    fn idx_from_char(&self, ch: char) -> Option<u8> {
+        match self {
+            // older encoders write zero as ' ', both are accepted
+            Self::Uu => (' '..='`').contains(&ch).then(|| (ch as u8 - b' ') & 63),
+            Self::Xx => XX.iter().position(|c| *c as char == ch).map(|i| i as u8),
+        }
+    }
+}
+
+/// a file that is framed by the begin and end lines
+#[derive(Debug, PartialEq, Clone)]
+pub struct UuFile {
+    /// the file name from the begin line
+    pub name: String,
+    /// the unix permissions from the begin line, e.g., 0o644
+    pub mode: u32,
+    /// the file contents
+    pub data: Vec<u8>,
+}
+
+impl UuFile {
+    /// creates a new file from its name, unix permissions and contents
+    pub fn new(name: impl Into<String>, mode: u32, data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            name: name.into(),
+            mode,
+            data: data.into(),
+        }
+    }
+}
+
+/// errors that can occur while decoding a uuencoded or xxencoded file
+#[derive(Debug, PartialEq)]
+pub enum UuError {
+    /// no "begin <mode> <name>" line was found
+    MissingBegin,
+    /// the begin line was found but its mode is not an octal number or its name is missing
+    /// .0 corresponds to the bad begin line
+    BadBeginLine(String),
+    /// a body line contains a char that is not part of the flavor's table
+    /// line is the 1 based line number
+    InvalidChar { ch: char, line: usize },
+    /// the body was not followed by the "end" line
+    MissingEnd,
+}
+
+/// produces the whole framed file: the begin line, the body lines, the zero count line
+/// and the end line
+#[cfg(feature = "encoding")]
+pub fn encode(file: &UuFile, flavor: Flavor) -> String {
+    let mut encoded = format!("begin {:03o} {}\n", file.mode, file.name);
+
+    file.data.chunks(LINE_LEN).for_each(|line| {
+        encoded.push(flavor.char_from_idx(line.len() as u8));
+        line.chunks(3).for_each(|chunk| {
+            encoded.extend(
+                crate::encoders::into_6bits_values(chunk)
+                    .into_iter()
+                    .map(|b| flavor.char_from_idx(b)),
+            )
+        });
+        encoded.push('\n');
+    });
+
+    encoded.push(flavor.char_from_idx(0));
+    encoded.push_str("\nend\n");
+
+    encoded
+}
+
+// parses "begin <mode> <name>"
+fn parse_begin(line: &str) -> Result<(u32, String), UuError> {
+    let bad = || UuError::BadBeginLine(line.into());
+    let mut parts = line.splitn(3, ' ').skip(1);
+    let mode = parts
+        .next()
+        .and_then(|m| u32::from_str_radix(m, 8).ok())
+        .ok_or_else(bad)?;
+    let name = parts.next().filter(|n| !n.is_empty()).ok_or_else(bad)?;
+
+    Ok((mode, name.into()))
+}
+
+/// parses a whole framed file
+/// any lines that come before the begin line, e.g., mail headers, are skipped
+/// and both "\n" and "\r\n" line endings are accepted
+///
+/// # Error
+/// returns an Err if the begin or end lines are missing or malformed,
+/// or if a body line contains a char that is not part of the flavor's table
+#[cfg(feature = "decoding")]
+pub fn decode(value: &str, flavor: Flavor) -> Result<UuFile, UuError> {
+    let mut lines = value
+        .lines()
+        .enumerate()
+        .skip_while(|(_, l)| !l.starts_with("begin "));
+    let (mode, name) = match lines.next() {
+        Some((_, line)) => parse_begin(line.trim_end())?,
+        None => return Err(UuError::MissingBegin),
+    };
+
+    let mut data = Vec::new();
+    for (idx, line) in lines {
+        // trailing spaces are zeros for uu, they are restored by the zero fill below
+        let line = line.trim_end();
+        if line == "end" {
+            return Ok(UuFile { name, mode, data });
+        }
+
+        let mut chars = line.chars();
+        let len = match chars.next() {
+            None => 0,
+            Some(ch) => flavor
+                .idx_from_char(ch)
+                .ok_or(UuError::InvalidChar { ch, line: idx + 1 })?
+                as usize,
+        };
+        if len == 0 {
+            continue;
+        }
+
+        let indices = chars
+            .map(|ch| {
+                flavor
+                    .idx_from_char(ch)
+                    .ok_or(UuError::InvalidChar { ch, line: idx + 1 })
+            })
+            .collect::<Result<Vec<u8>, UuError>>()?;
+
+        let start = data.len();
+        indices
+            .chunks(4)
+            .for_each(|chunk| data.extend(crate::decoders::into_8bits_values(chunk)));
+        // the chars that were trimmed off of a short line are zeros
+        data.resize(start + len, 0);
+    }
+
+    Err(UuError::MissingEnd)
+}
@@ -0,0 +1,187 @@
+mod encoder {
+    use makura::armor::{self, Armor, ArmorKind};
+
+    #[test]
+    fn test0() {
+        let input = Armor::new(ArmorKind::Signature, "");
+        let output = "-----BEGIN PGP SIGNATURE-----\n\n=twTO\n-----END PGP SIGNATURE-----\n";
+
+        assert_eq!(armor::encode(&input), output);
+    }
+
+    // gpg --store --armor --compress-algo none of a "hello, world" file
+    #[test]
+    fn test1() {
+        let input = Armor::new(
+            ArmorKind::Message,
+            makura::base64!("rBJiAGrVoSVoZWxsbywgd29ybGQ="),
+        );
+        let output = "-----BEGIN PGP MESSAGE-----\n\n\
+            rBJiAGrVoSVoZWxsbywgd29ybGQ=\n\
+            =9i7M\n\
+            -----END PGP MESSAGE-----\n";
+
+        assert_eq!(armor::encode(&input), output);
+    }
+
+    // gpg --enarmor writes the same body and checksum
+    #[test]
+    fn test2() {
+        let input = Armor::new(ArmorKind::PublicKey, [0xff; 60])
+            .header("Version", "makura")
+            .header("Comment", "a full body line");
+        let output = "-----BEGIN PGP PUBLIC KEY BLOCK-----\n\
+            Version: makura\n\
+            Comment: a full body line\n\
+            \n\
+            ////////////////////////////////////////////////////////////////\n\
+            ////////////////\n\
+            =y/sM\n\
+            -----END PGP PUBLIC KEY BLOCK-----\n";
+
+        assert_eq!(armor::encode(&input), output);
+    }
+
+    #[test]
+    fn test_parts() {
+        let kind = ArmorKind::MessagePart {
+            part: 2,
+            total: Some(3),
+        };
+
+        assert_eq!(kind.label(), "PGP MESSAGE, PART 2/3");
+
+        let kind = ArmorKind::MessagePart {
+            part: 4,
+            total: None,
+        };
+
+        assert!(
+            armor::encode(&Armor::new(kind, ""))
+                .starts_with("-----BEGIN PGP MESSAGE, PART 4-----\n")
+        );
+    }
+}
+
+mod decoder {
+    use makura::armor::{self, Armor, ArmorError, ArmorKind};
+
+    #[test]
+    fn test0() {
+        let input = "-----BEGIN PGP MESSAGE-----\n\n\
+            ywtiBWVtcHR5atWhJQ==\n\
+            =GTxW\n\
+            -----END PGP MESSAGE-----\n";
+        let output = Armor::new(ArmorKind::Message, makura::base64!("ywtiBWVtcHR5atWhJQ=="));
+
+        assert_eq!(armor::decode(input).unwrap(), output);
+    }
+
+    // leading lines, headers, "\r\n" line endings and a message part label
+    #[test]
+    fn test1() {
+        let input = "From: someone\r\n\r\n\
+            -----BEGIN PGP MESSAGE, PART 1/2-----\r\n\
+            Version: GnuPG v2\r\n\
+            Comment: hello world\r\n\
+            \r\n\
+            rBJiAGrVoSVoZWxsbywgd29ybGQ=\r\n\
+            =9i7M\r\n\
+            -----END PGP MESSAGE, PART 1/2-----\r\n";
+        let output = armor::decode(input).unwrap();
+
+        assert_eq!(
+            output.kind,
+            ArmorKind::MessagePart {
+                part: 1,
+                total: Some(2)
+            }
+        );
+        assert_eq!(output.version(), Some("GnuPG v2"));
+        assert_eq!(output.comment(), Some("hello world"));
+        assert_eq!(output.data, makura::base64!("rBJiAGrVoSVoZWxsbywgd29ybGQ="));
+    }
+
+    // the checksum line is optional
+    #[test]
+    fn test2() {
+        let input = "-----BEGIN PGP SIGNATURE-----\n\nZm9vYmFy\n-----END PGP SIGNATURE-----";
+
+        assert_eq!(armor::decode(input).unwrap().data, b"foobar");
+    }
+
+    #[test]
+    fn test_clearsign() {
+        let signature = armor::encode(&Armor::new(ArmorKind::Signature, "foobar"));
+        let input = format!(
+            "-----BEGIN PGP SIGNED MESSAGE-----\nHash: SHA256\n\n- -----dash escaped\n{signature}"
+        );
+
+        assert_eq!(
+            armor::decode(&input).unwrap(),
+            Armor::new(ArmorKind::Signature, "foobar")
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let data = (0..=255).cycle().take(1000).collect::<Vec<u8>>();
+        let input = Armor::new(ArmorKind::PrivateKey, data).header("Comment", "roundtrip");
+
+        assert_eq!(armor::decode(&armor::encode(&input)).unwrap(), input);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            armor::decode("Zm9vYmFy"),
+            Err(ArmorError::MissingBegin)
+        ));
+        assert!(matches!(
+            armor::decode("-----BEGIN PGP NOTHING-----\n\nZm9vYmFy\n-----END PGP NOTHING-----"),
+            Err(ArmorError::UnknownKind(label)) if label == "PGP NOTHING"
+        ));
+        assert!(matches!(
+            armor::decode("-----BEGIN PGP MESSAGE-----\nVersion\n\nZm9vYmFy\n-----END PGP MESSAGE-----"),
+            Err(ArmorError::BadHeader(line)) if line == "Version"
+        ));
+        assert!(matches!(
+            armor::decode("-----BEGIN PGP MESSAGE-----\n\nZm9vYmFy\n-----END PGP SIGNATURE-----"),
+            Err(ArmorError::MissingEnd)
+        ));
+        assert!(matches!(
+            armor::decode("-----BEGIN PGP MESSAGE-----\n\nZm9v*mFy\n-----END PGP MESSAGE-----"),
+            Err(ArmorError::DecodeError(_))
+        ));
+        assert!(matches!(
+            armor::decode(
+                "-----BEGIN PGP MESSAGE-----\n\nrBJiAGrVoSVoZWxsbywgd29ybGQ=\n=9i7N\n-----END PGP MESSAGE-----"
+            ),
+            Err(ArmorError::BadChecksum {
+                expected: 0xf62ecd,
+                found: 0xf62ecc
+            })
+        ));
+    }
+
+    #[test]
+    fn test_error() {
+        use std::error::Error;
+
+        let err =
+            armor::decode("-----BEGIN PGP MESSAGE-----\n\nZm9v*mFy\n-----END PGP MESSAGE-----")
+                .unwrap_err();
+
+        assert!(err.source().unwrap().is::<makura::DecodeError>());
+
+        let err: Box<dyn Error> = armor::decode("Zm9vYmFy").unwrap_err().into();
+
+        assert_eq!(err.to_string(), "no armor begin line");
+    }
+
+    #[test]
+    fn test_crc24() {
+        assert_eq!(armor::crc24(b""), 0xb704ce);
+        assert_eq!(armor::crc24(b"123456789"), 0x21cf02);
+    }
+}
@@ -63,6 +63,15 @@ mod encoder {
 
         assert_eq!(enc.encode(input), output);
     }
+
+    #[test]
+    fn test_lowercase() {
+        let input = "foobar";
+        let output = "666f6f626172";
+        let enc = Encoder::base16().lowercase();
+
+        assert_eq!(enc.encode(input), output);
+    }
 }
 
 mod decoder {
@@ -124,4 +133,20 @@ mod decoder {
 
         assert_eq!(Decoder::decode_deduce(output).unwrap(), input);
     }
+
+    #[test]
+    fn test_lowercase() {
+        let input = "foobar";
+        let output = "666f6f626172";
+
+        assert_eq!(Decoder::decode(output, BASE16).unwrap(), input);
+    }
+
+    #[test]
+    fn test_mixed_case() {
+        let input = "foobar";
+        let output = "666f6F626172";
+
+        assert_eq!(Decoder::decode(output, BASE16).unwrap(), input);
+    }
 }
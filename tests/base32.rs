@@ -63,6 +63,24 @@ mod encoder {
 
         assert_eq!(enc.encode(input), output);
     }
+
+    #[test]
+    fn test_lowercase() {
+        let input = "foobar";
+        let output = "mzxw6ytboi======";
+        let enc = Encoder::base32().lowercase();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    // trailing zero bytes are data, not padding
+    #[test]
+    fn test7() {
+        let enc = Encoder::base32();
+
+        assert_eq!(enc.encode_bytes([0, 0, 0, 0, 0]), "AAAAAAAA");
+        assert_eq!(enc.encode_bytes([0x66, 0]), "MYAA====");
+    }
 }
 
 mod decoder {
@@ -118,4 +136,32 @@ mod decoder {
         let output = "MZXW6YTBOI======";
         assert_eq!(Decoder::decode_deduce(output).unwrap(), input);
     }
+
+    #[test]
+    fn test_lowercase() {
+        let input = "foobar";
+        let output = "mzxw6ytboi======";
+
+        assert_eq!(Decoder::decode(output, BASE32).unwrap(), input);
+    }
+
+    #[test]
+    fn test_mixed_case() {
+        let input = "foobar";
+        let output = "mzxw6YTBOI======";
+
+        assert_eq!(Decoder::decode(output, BASE32).unwrap(), input);
+    }
+
+    #[test]
+    fn test7() {
+        assert_eq!(
+            Decoder::decode_to_bytes("AAAAAAAA", BASE32).unwrap(),
+            [0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            Decoder::decode_to_bytes("MYAA====", BASE32).unwrap(),
+            [0x66, 0]
+        );
+    }
 }
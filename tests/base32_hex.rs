@@ -54,6 +54,15 @@ mod encoder {
 
         assert_eq!(enc.encode(input), output);
     }
+
+    #[test]
+    fn test_lowercase() {
+        let input = "foobar";
+        let output = "cpnmuoj1e8======";
+        let enc = Encoder::base32_hex().lowercase();
+
+        assert_eq!(enc.encode(input), output);
+    }
 }
 
 mod decoder {
@@ -118,4 +127,20 @@ mod decoder {
 
         assert_eq!(Decoder::decode_deduce(output).unwrap(), input);
     }
+
+    #[test]
+    fn test_lowercase() {
+        let input = "foobar";
+        let output = "cpnmuoj1e8======";
+
+        assert_eq!(Decoder::decode(output, BASE32HEX).unwrap(), input);
+    }
+
+    #[test]
+    fn test_mixed_case() {
+        let input = "foobar";
+        let output = "cpnmuOJ1E8======";
+
+        assert_eq!(Decoder::decode(output, BASE32HEX).unwrap(), input);
+    }
 }
@@ -0,0 +1,84 @@
+mod encoder {
+    use makura::Encoder;
+
+    #[test]
+    fn test0() {
+        let input = "";
+        let output = "";
+        let enc = Encoder::base32_nsec3();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "f";
+        let output = "co";
+        let enc = Encoder::base32_nsec3();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = "foob";
+        let output = "cpnmuog";
+        let enc = Encoder::base32_nsec3();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test3() {
+        let input = "foobar";
+        let output = "cpnmuoj1e8";
+        let enc = Encoder::base32_nsec3();
+
+        assert_eq!(enc.encode(input), output);
+    }
+}
+
+mod decoder {
+    use makura::BASE32NSEC3;
+    use makura::Decoder;
+
+    #[test]
+    fn test0() {
+        let input = "cpng";
+        let output = "fo";
+
+        assert_eq!(Decoder::decode(input, BASE32NSEC3).unwrap(), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "cpnmuoj1e8";
+        let output = "foobar";
+
+        assert_eq!(Decoder::decode(input, BASE32NSEC3).unwrap(), output);
+    }
+
+    #[test]
+    fn test2() {
+        // owner names are case insensitive
+        let input = "CPNMUOJ1E8";
+        let output = "foobar";
+
+        assert_eq!(Decoder::decode(input, BASE32NSEC3).unwrap(), output);
+    }
+
+    #[test]
+    fn test3() {
+        // a sha-1 nsec3 owner name hash, taken from rfc 5155 appendix a
+        let input = "0p9mhaveqvm6t7vbl5lop2u3t2rp3tom";
+        let bytes = Decoder::decode_to_bytes(input, BASE32NSEC3).unwrap();
+
+        assert_eq!(bytes.len(), 20);
+        assert_eq!(makura::Encoder::base32_nsec3().encode_bytes(bytes), input);
+    }
+
+    #[test]
+    fn test4() {
+        assert!(Decoder::decode("cpng====", BASE32NSEC3).is_err());
+    }
+}
@@ -0,0 +1,148 @@
+mod encoder {
+    use makura::Encoder;
+
+    #[test]
+    fn test0() {
+        let input = "";
+        let output = "";
+        let enc = Encoder::base32_z();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "f";
+        let output = "ca";
+        let enc = Encoder::base32_z();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = "foob";
+        let output = "c3zs6ao";
+        let enc = Encoder::base32_z();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test3() {
+        let input = "foobar";
+        let output = "c3zs6aubqe";
+        let enc = Encoder::base32_z();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test_bits1() {
+        let enc = Encoder::base32_z();
+
+        assert_eq!(enc.encode_bits([0x00], 1), "y");
+        assert_eq!(enc.encode_bits([0x80], 1), "o");
+    }
+
+    #[test]
+    fn test_bits2() {
+        let enc = Encoder::base32_z();
+
+        assert_eq!(enc.encode_bits([0x40], 2), "e");
+        assert_eq!(enc.encode_bits([0xc0], 2), "a");
+    }
+
+    #[test]
+    fn test_bits10() {
+        let enc = Encoder::base32_z();
+
+        assert_eq!(enc.encode_bits([0x00, 0x00], 10), "yy");
+        assert_eq!(enc.encode_bits([0x80, 0x80], 10), "on");
+    }
+
+    #[test]
+    fn test_bits20() {
+        let input = [0x8b, 0x88, 0x80];
+        let output = "tqre";
+        let enc = Encoder::base32_z();
+
+        assert_eq!(enc.encode_bits(input, 20), output);
+    }
+
+    #[test]
+    fn test_bits30() {
+        let input = [0xf5, 0x57, 0xbd, 0x0c];
+        let output = "6im54d";
+        let enc = Encoder::base32_z();
+
+        assert_eq!(enc.encode_bits(input, 30), output);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bits_overflow() {
+        let enc = Encoder::base32_z();
+
+        enc.encode_bits([0xff], 9);
+    }
+}
+
+mod decoder {
+    use makura::BASE32Z;
+    use makura::Decoder;
+
+    #[test]
+    fn test0() {
+        let input = "f";
+        let output = "ca";
+
+        assert_eq!(Decoder::decode(output, BASE32Z).unwrap(), input);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "fooba";
+        let output = "c3zs6aub";
+
+        assert_eq!(Decoder::decode(output, BASE32Z).unwrap(), input);
+    }
+
+    #[test]
+    fn test2() {
+        let input = "hello, world";
+        let output = "pb1sa5dxfoo8q551pt1y";
+
+        assert_eq!(Decoder::decode(output, BASE32Z).unwrap(), input);
+    }
+
+    #[test]
+    fn test_bits10() {
+        let input = [0x80, 0x80];
+        let output = "on";
+
+        assert_eq!(Decoder::decode_bits(output, BASE32Z, 10).unwrap(), input);
+    }
+
+    #[test]
+    fn test_bits24() {
+        let input = [0xd4, 0x7a, 0x04];
+        let output = "4t7ye";
+
+        assert_eq!(Decoder::decode_bits(output, BASE32Z, 24).unwrap(), input);
+    }
+
+    #[test]
+    fn test_bits_too_long() {
+        let output = "on";
+
+        assert!(Decoder::decode_bits(output, BASE32Z, 11).is_err());
+    }
+
+    #[test]
+    fn test_padding() {
+        let output = "ca======";
+
+        assert!(Decoder::decode(output, BASE32Z).is_err());
+    }
+}
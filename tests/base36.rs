@@ -0,0 +1,85 @@
+mod encoder {
+    use makura::Encoder;
+
+    #[test]
+    fn test0() {
+        let input = "";
+        let output = "";
+        let enc = Encoder::base36();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "f";
+        let output = "2U";
+        let enc = Encoder::base36();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = "foobar";
+        let output = "13X8YD7YWI";
+        let enc = Encoder::base36();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test3() {
+        let input = "Hello World";
+        let output = "AZW5BZ2XP56M4QYCK";
+        let enc = Encoder::base36();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test_leading_zeros() {
+        let input = "\0\0ab";
+        let output = "00J8I";
+        let enc = Encoder::base36();
+
+        assert_eq!(enc.encode(input), output);
+    }
+}
+
+mod decoder {
+    use makura::BASE36;
+    use makura::Decoder;
+
+    #[test]
+    fn test0() {
+        let input = "f";
+        let output = "2U";
+
+        assert_eq!(Decoder::decode(output, BASE36).unwrap(), input);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "Hello World";
+        let output = "AZW5BZ2XP56M4QYCK";
+
+        assert_eq!(Decoder::decode(output, BASE36).unwrap(), input);
+    }
+
+    #[test]
+    fn test_leading_zeros() {
+        let input = "\0\0ab";
+        let output = "00J8I";
+
+        assert_eq!(Decoder::decode(output, BASE36).unwrap(), input);
+    }
+
+    #[test]
+    fn test_zeros_only() {
+        let input = "\0\0\0";
+        let output = "000";
+
+        assert_eq!(Decoder::decode(output, BASE36).unwrap(), input);
+    }
+}
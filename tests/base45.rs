@@ -29,10 +29,20 @@ mod encoder {
 
         assert_eq!(enc.encode(input), output);
     }
+
+    // chunks whose base 45 digits are zeros
+    #[test]
+    fn test3() {
+        let enc = Encoder::base45();
+
+        assert_eq!(enc.encode_bytes([0, 0]), "000");
+        assert_eq!(enc.encode_bytes([0, 45]), "010");
+        assert_eq!(enc.encode_bytes([0]), "00");
+    }
 }
 
 mod decoder {
-    use makura::Decoder;
+    use makura::{BASE45, Decoder};
 
     #[test]
     fn test0() {
@@ -41,4 +51,12 @@ mod decoder {
 
         assert_eq!(Decoder::decode_deduce(output).unwrap(), input);
     }
+
+    #[test]
+    fn test1() {
+        assert_eq!(Decoder::decode_to_bytes("000", BASE45).unwrap(), [0, 0]);
+        assert_eq!(Decoder::decode_to_bytes("010", BASE45).unwrap(), [0, 45]);
+        assert_eq!(Decoder::decode_to_bytes("00", BASE45).unwrap(), [0]);
+        assert_eq!(Decoder::decode_to_bytes("U5", BASE45).unwrap(), [0xff]);
+    }
 }
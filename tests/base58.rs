@@ -0,0 +1,68 @@
+mod encoder {
+    use makura::Encoder;
+
+    #[test]
+    fn test0() {
+        let input = "";
+        let output = "";
+        let enc = Encoder::base58();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "Hello World!";
+        let output = "2NEpo7TZRRrLZSi2U";
+        let enc = Encoder::base58();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = "The quick brown fox jumps over the lazy dog.";
+        let output = "USm3fpXnKG5EUBx2ndxBDMPVciP5hGey2Jh4NDv6gmeo1LkMeiKrLJUUBk6Z";
+        let enc = Encoder::base58();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test_leading_zeros() {
+        let input = "\0\0ab";
+        let output = "118Qq";
+        let enc = Encoder::base58();
+
+        assert_eq!(enc.encode(input), output);
+    }
+}
+
+mod decoder {
+    use makura::BASE58;
+    use makura::Decoder;
+
+    #[test]
+    fn test0() {
+        let input = "Hello World!";
+        let output = "2NEpo7TZRRrLZSi2U";
+
+        assert_eq!(Decoder::decode(output, BASE58).unwrap(), input);
+    }
+
+    #[test]
+    fn test_leading_zeros() {
+        let input = "\0\0ab";
+        let output = "118Qq";
+
+        assert_eq!(Decoder::decode(output, BASE58).unwrap(), input);
+    }
+
+    #[test]
+    fn test_excluded_chars() {
+        // 0, O, I and l are not part of the table
+        let output = "0OIl";
+
+        assert!(Decoder::decode(output, BASE58).is_err());
+    }
+}
@@ -0,0 +1,98 @@
+mod encoder {
+    use makura::Encoder;
+
+    #[test]
+    fn test0() {
+        let input = "";
+        let output = "";
+        let enc = Encoder::base62();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "f";
+        let output = "1e";
+        let enc = Encoder::base62();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = "foobar";
+        let output = "VytN8Wjy";
+        let enc = Encoder::base62();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test3() {
+        let input = "Hello World";
+        let output = "73XpUgyMwkGr29M";
+        let enc = Encoder::base62();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test_leading_zeros() {
+        let input = "\0\0ab";
+        let output = "006U6";
+        let enc = Encoder::base62();
+
+        assert_eq!(enc.encode(input), output);
+    }
+}
+
+mod decoder {
+    use makura::BASE62;
+    use makura::Decoder;
+
+    #[test]
+    fn test0() {
+        let input = "f";
+        let output = "1e";
+
+        assert_eq!(Decoder::decode(output, BASE62).unwrap(), input);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "Hello World";
+        let output = "73XpUgyMwkGr29M";
+
+        assert_eq!(Decoder::decode(output, BASE62).unwrap(), input);
+    }
+
+    #[test]
+    fn test_leading_zeros() {
+        let input = "\0\0ab";
+        let output = "006U6";
+
+        assert_eq!(Decoder::decode(output, BASE62).unwrap(), input);
+    }
+
+    #[test]
+    fn test_zeros_only() {
+        let input = "\0\0\0";
+        let output = "000";
+
+        assert_eq!(Decoder::decode(output, BASE62).unwrap(), input);
+    }
+}
+
+mod round_trip {
+    use makura::BASE62;
+    use makura::{Decoder, Encoder};
+
+    #[test]
+    fn test0() {
+        let input = "\0🍜 makura \0";
+        let enc = Encoder::base62();
+
+        assert_eq!(Decoder::decode(enc.encode(input), BASE62).unwrap(), input);
+    }
+}
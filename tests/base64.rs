@@ -63,10 +63,20 @@ mod encoder {
 
         assert_eq!(enc.encode(input), output);
     }
+
+    // trailing zero bytes are data, not padding
+    #[test]
+    fn test8() {
+        let enc = Encoder::base64();
+
+        assert_eq!(enc.encode_bytes([0, 0, 0]), "AAAA");
+        assert_eq!(enc.encode_bytes([0x66, 0]), "ZgA=");
+        assert_eq!(enc.encode_bytes([0]), "AA==");
+    }
 }
 
 mod decoder {
-    use makura::Decoder;
+    use makura::{BASE64, Decoder};
 
     #[test]
     fn test0() {
@@ -123,4 +133,17 @@ mod decoder {
 
         assert_eq!(Decoder::decode_deduce(output).unwrap(), input);
     }
+
+    #[test]
+    fn test7() {
+        assert_eq!(Decoder::decode_to_bytes("AAAA", BASE64).unwrap(), [0, 0, 0]);
+        assert_eq!(Decoder::decode_to_bytes("ZgA=", BASE64).unwrap(), [0x66, 0]);
+        assert_eq!(Decoder::decode_to_bytes("AA==", BASE64).unwrap(), [0]);
+    }
+
+    // padding can only come at the end
+    #[test]
+    fn test8() {
+        assert!(Decoder::decode_to_bytes("Zg==Zg==", BASE64).is_err());
+    }
 }
@@ -0,0 +1,221 @@
+mod encoder {
+    use makura::Encoder;
+
+    #[test]
+    fn test0() {
+        let input = "";
+        let output = "";
+        let enc = Encoder::base64_bcrypt();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "foob";
+        let output = "Xk7tWe";
+        let enc = Encoder::base64_bcrypt();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = [0xfb, 0xff, 0xbf, 0x00];
+        let output = "8989..";
+        let enc = Encoder::base64_bcrypt();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test3() {
+        let input = "fooba";
+        let output = "axqPW34";
+        let enc = Encoder::base64_crypt();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test4() {
+        let input = [0xfb, 0xff, 0xbf, 0x00];
+        let output = "vzzj..";
+        let enc = Encoder::base64_crypt();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    // the checksum of `openssl passwd -1 -salt saltsalt password`,
+    // the md5 digest bytes after md5-crypt's own shuffling
+    #[test]
+    fn test_md5_crypt() {
+        let input = [
+            0xf6, 0x3b, 0x62, 0xfb, 0x09, 0xf1, 0x0a, 0x6d, 0x5e, 0x80, 0xf3, 0xaf, 0xf1, 0x93,
+            0x9b, 0x56,
+        ];
+        let output = "qjXMvbEw8oaL.CzflDtaK/";
+        let enc = Encoder::base64_crypt();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test5() {
+        let input = [0xfb, 0xff, 0xbf, 0x00];
+        let output = "+,+,AA";
+        let enc = Encoder::base64_imap();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test6() {
+        let input = [0xfb, 0xff, 0xbf, 0x00];
+        let output = "_:_:AA";
+        let enc = Encoder::base64_xml_name();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test7() {
+        let input = [0xfb, 0xff, 0xbf, 0x00];
+        let output = ".-.-AA";
+        let enc = Encoder::base64_xml_nmtoken();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test8() {
+        let input = [0xfb, 0xff, 0xbf, 0x00];
+        let output = "._._AA--";
+        let enc = Encoder::base64_y64();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    #[test]
+    fn test9() {
+        let input = "fooba";
+        let output = "Zm9vYmE-";
+        let enc = Encoder::base64_y64();
+
+        assert_eq!(enc.encode(input), output);
+    }
+}
+
+mod decoder {
+    use makura::Decoder;
+    use makura::{
+        BASE64BCRYPT, BASE64CRYPT, BASE64IMAP, BASE64XMLNAME, BASE64XMLNMTOKEN, BASE64Y64,
+    };
+
+    #[test]
+    fn test0() {
+        let input = "Xk7tWkDw";
+        let output = "foobar";
+
+        assert_eq!(Decoder::decode(input, BASE64BCRYPT).unwrap(), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "8989..";
+        let output = [0xfb, 0xff, 0xbf, 0x00];
+
+        assert_eq!(
+            Decoder::decode_to_bytes(input, BASE64BCRYPT).unwrap(),
+            output
+        );
+    }
+
+    #[test]
+    fn test2() {
+        let input = "ax4";
+        let output = "fo";
+
+        assert_eq!(Decoder::decode(input, BASE64CRYPT).unwrap(), output);
+    }
+
+    #[test]
+    fn test3() {
+        let input = "vzzj..";
+        let output = [0xfb, 0xff, 0xbf, 0x00];
+
+        assert_eq!(
+            Decoder::decode_to_bytes(input, BASE64CRYPT).unwrap(),
+            output
+        );
+    }
+
+    // $1$saltsalt$qjXMvbEw8oaL.CzflDtaK/
+    #[test]
+    fn test_md5_crypt() {
+        let input = "qjXMvbEw8oaL.CzflDtaK/";
+        let output = [
+            0xf6, 0x3b, 0x62, 0xfb, 0x09, 0xf1, 0x0a, 0x6d, 0x5e, 0x80, 0xf3, 0xaf, 0xf1, 0x93,
+            0x9b, 0x56,
+        ];
+
+        assert_eq!(
+            Decoder::decode_to_bytes(input, BASE64CRYPT).unwrap(),
+            output
+        );
+    }
+
+    #[test]
+    fn test4() {
+        let input = "+,+,AA";
+        let output = [0xfb, 0xff, 0xbf, 0x00];
+
+        assert_eq!(Decoder::decode_to_bytes(input, BASE64IMAP).unwrap(), output);
+    }
+
+    #[test]
+    fn test5() {
+        let input = "_:_:AA";
+        let output = [0xfb, 0xff, 0xbf, 0x00];
+
+        assert_eq!(
+            Decoder::decode_to_bytes(input, BASE64XMLNAME).unwrap(),
+            output
+        );
+    }
+
+    #[test]
+    fn test6() {
+        let input = ".-.-AA";
+        let output = [0xfb, 0xff, 0xbf, 0x00];
+
+        assert_eq!(
+            Decoder::decode_to_bytes(input, BASE64XMLNMTOKEN).unwrap(),
+            output
+        );
+    }
+
+    #[test]
+    fn test7() {
+        let input = "Zm9vYg--";
+        let output = "foob";
+
+        assert_eq!(Decoder::decode(input, BASE64Y64).unwrap(), output);
+    }
+
+    #[test]
+    fn test8() {
+        let input = "._._";
+        let output = [0xfb, 0xff, 0xbf];
+
+        assert_eq!(Decoder::decode_to_bytes(input, BASE64Y64).unwrap(), output);
+    }
+
+    #[test]
+    fn test9() {
+        // the unpadded profiles can't contain '=' chars
+        assert!(Decoder::decode("Zm8=", BASE64IMAP).is_err());
+        // '/' belongs to base 64, not to the imap profile
+        assert!(Decoder::decode("Zm9/", BASE64IMAP).is_err());
+    }
+}
@@ -0,0 +1,183 @@
+mod encoder {
+    use makura::Encoder;
+
+    #[test]
+    fn test0() {
+        let input = "";
+        let output = "";
+        let enc = Encoder::base85();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "f";
+        let output = "W&";
+        let enc = Encoder::base85();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = "Hello, World!";
+        let output = "NM&qnZ!92JZ*pv8Ap";
+        let enc = Encoder::base85();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test_ascii0() {
+        let input = "";
+        let output = "<~~>";
+        let enc = Encoder::base85_ascii();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test_ascii1() {
+        let input = "foobar";
+        let output = "<~AoDTs@<)~>";
+        let enc = Encoder::base85_ascii();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test_ascii2() {
+        let input = "Hello, World!";
+        let output = "<~87cURD_*#4DfTZ)+T~>";
+        let enc = Encoder::base85_ascii();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test_ascii_zeros() {
+        let input = "\0\0\0\0ab";
+        let output = "<~z@:B~>";
+        let enc = Encoder::base85_ascii();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test_ascii_partial_zeros() {
+        // only full groups of zeros are compressed
+        let input = "\0\0";
+        let output = "<~!!!~>";
+        let enc = Encoder::base85_ascii();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test_z0() {
+        let input = [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b];
+        let output = "HelloWorld";
+        let enc = Encoder::base85_z();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+
+    // z85 only encodes whole chunks of 4 bytes
+    #[test]
+    #[should_panic]
+    fn test_z1() {
+        let enc = Encoder::base85_z();
+
+        enc.encode("foobar");
+    }
+}
+
+mod decoder {
+    use makura::{BASE85, BASE85ASCII, BASE85Z};
+    use makura::{DecodeError, Decoder};
+
+    #[test]
+    fn test0() {
+        let input = "Hello, World!";
+        let output = "NM&qnZ!92JZ*pv8Ap";
+
+        assert_eq!(Decoder::decode(output, BASE85).unwrap(), input);
+    }
+
+    #[test]
+    fn test_bad_len() {
+        let output = "NM&qnZ";
+
+        assert!(Decoder::decode(output, BASE85).is_err());
+    }
+
+    #[test]
+    fn test_overflow() {
+        let output = "~~~~~";
+
+        assert!(Decoder::decode(output, BASE85).is_err());
+    }
+
+    #[test]
+    fn test_ascii0() {
+        let input = "Hello, World!";
+        let output = "<~87cURD_*#4DfTZ)+T~>";
+
+        assert_eq!(Decoder::decode(output, BASE85ASCII).unwrap(), input);
+    }
+
+    #[test]
+    fn test_ascii_unframed() {
+        let input = "foobar";
+        let output = "AoDTs@<)~>";
+
+        assert_eq!(Decoder::decode(output, BASE85ASCII).unwrap(), input);
+    }
+
+    #[test]
+    fn test_ascii_whitespace() {
+        let input = "Hello, World!";
+        let output = "<~87cUR\nD_*#4\r\nDfTZ) +T~>";
+
+        assert_eq!(Decoder::decode(output, BASE85ASCII).unwrap(), input);
+    }
+
+    #[test]
+    fn test_ascii_zeros() {
+        let input = "\0\0\0\0ab";
+        let output = "<~z@:B~>";
+
+        assert_eq!(Decoder::decode(output, BASE85ASCII).unwrap(), input);
+    }
+
+    #[test]
+    fn test_ascii_misplaced_z() {
+        let output = "<~@:zB~>";
+
+        assert!(Decoder::decode(output, BASE85ASCII).is_err());
+    }
+
+    #[test]
+    fn test_z0() {
+        let input = [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b];
+        let output = "HelloWorld";
+
+        assert_eq!(Decoder::decode_to_bytes(output, BASE85Z).unwrap(), input);
+    }
+
+    // z85 only decodes whole groups of 5 digits
+    #[test]
+    fn test_z1() {
+        let output = "nm=QNz.92jz/PV8aP";
+
+        assert!(matches!(
+            Decoder::decode(output, BASE85Z),
+            Err(DecodeError::BadLenForBase(17))
+        ));
+        assert!(matches!(
+            Decoder::decode_in_place(&mut output.as_bytes().to_owned(), BASE85Z),
+            Err(DecodeError::BadLenForBase(17))
+        ));
+    }
+}
@@ -0,0 +1,85 @@
+mod encoder {
+    use makura::Encoder;
+
+    #[test]
+    fn test0() {
+        let input = "";
+        let output = "";
+        let enc = Encoder::base91();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "f";
+        let output = "LB";
+        let enc = Encoder::base91();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = "foobar";
+        let output = "dr/2s)uC";
+        let enc = Encoder::base91();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test3() {
+        let input = "Hello, World!";
+        let output = ">OwJh>}AQ;r@@Y?F";
+        let enc = Encoder::base91();
+
+        assert_eq!(enc.encode(input), output);
+    }
+
+    #[test]
+    fn test4() {
+        let input = [0xff; 5];
+        let output = "B\"B\"B\"B";
+        let enc = Encoder::base91();
+
+        assert_eq!(enc.encode_bytes(input), output);
+    }
+}
+
+mod decoder {
+    use makura::BASE91;
+    use makura::Decoder;
+
+    #[test]
+    fn test0() {
+        let input = "test";
+        let output = "fPNKd";
+
+        assert_eq!(Decoder::decode(output, BASE91).unwrap(), input);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "Hello, World!";
+        let output = ">OwJh>}AQ;r@@Y?F";
+
+        assert_eq!(Decoder::decode(output, BASE91).unwrap(), input);
+    }
+
+    #[test]
+    fn test2() {
+        let input = [0xff; 5];
+        let output = "B\"B\"B\"B";
+
+        assert_eq!(Decoder::decode_to_bytes(output, BASE91).unwrap(), input);
+    }
+
+    #[test]
+    fn test3() {
+        let input = "\0\0\0\0";
+        let output = "AAAAA";
+
+        assert_eq!(Decoder::decode(output, BASE91).unwrap(), input);
+    }
+}
@@ -0,0 +1,144 @@
+mod encoder {
+    use makura::bech32::{self, Bech32Error, Variant};
+
+    #[test]
+    fn test0() {
+        let output = "a12uel5l";
+
+        assert_eq!(bech32::encode("a", [], Variant::Bech32).unwrap(), output);
+    }
+
+    #[test]
+    fn test1() {
+        let output = "a1lqfn3a";
+
+        assert_eq!(bech32::encode("A", [], Variant::Bech32m).unwrap(), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = [
+            0x3b, 0xf0, 0xc6, 0x3f, 0xcb, 0x93, 0x46, 0x34, 0x07, 0xaf, 0x97, 0xa5, 0xe5, 0xee,
+            0x64, 0xfa, 0x88, 0x3d, 0x10, 0x7e, 0xf9, 0xe5, 0x58, 0x47, 0x2c, 0x4e, 0xb9, 0xaa,
+            0xae, 0xfa, 0x45, 0x9d,
+        ];
+        let output = "npub180cvv07tjdrrgpa0j7j7tmnyl2yr6yr7l8j4s3evf6u64th6gkwsyjh6w6";
+
+        assert_eq!(
+            bech32::encode("npub", input, Variant::Bech32).unwrap(),
+            output
+        );
+    }
+
+    #[test]
+    fn test_5bits() {
+        let input = (0..32).collect::<Vec<u8>>();
+        let output = "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw";
+
+        assert_eq!(
+            bech32::encode_5bits("abcdef", &input, Variant::Bech32).unwrap(),
+            output
+        );
+    }
+
+    #[test]
+    fn test_mixed_case_hrp() {
+        assert_eq!(
+            bech32::encode("aB", [], Variant::Bech32),
+            Err(Bech32Error::MixedCase)
+        );
+    }
+
+    #[test]
+    fn test_too_long() {
+        assert_eq!(
+            bech32::encode("a", [0; 60], Variant::Bech32),
+            Err(Bech32Error::TooLong(104))
+        );
+    }
+}
+
+mod decoder {
+    use makura::bech32::{self, Bech32Error, Variant};
+
+    #[test]
+    fn test0() {
+        for input in [
+            "A12UEL5L",
+            "a12uel5l",
+            "an83characterlonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1tt5tgs",
+            "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw",
+            "split1checkupstagehandshakeupstreamerranterredcaperred2y9e3w",
+            "?1ezyfcl",
+        ] {
+            assert_eq!(bech32::decode_5bits(input).unwrap().2, Variant::Bech32);
+        }
+    }
+
+    #[test]
+    fn test1() {
+        for input in [
+            "A1LQFN3A",
+            "a1lqfn3a",
+            "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx",
+            "?1v759aa",
+        ] {
+            assert_eq!(bech32::decode_5bits(input).unwrap().2, Variant::Bech32m);
+        }
+    }
+
+    #[test]
+    fn test2() {
+        let input = [
+            0x3b, 0xf0, 0xc6, 0x3f, 0xcb, 0x93, 0x46, 0x34, 0x07, 0xaf, 0x97, 0xa5, 0xe5, 0xee,
+            0x64, 0xfa, 0x88, 0x3d, 0x10, 0x7e, 0xf9, 0xe5, 0x58, 0x47, 0x2c, 0x4e, 0xb9, 0xaa,
+            0xae, 0xfa, 0x45, 0x9d,
+        ];
+        let output = "npub180cvv07tjdrrgpa0j7j7tmnyl2yr6yr7l8j4s3evf6u64th6gkwsyjh6w6";
+
+        assert_eq!(
+            bech32::decode(output).unwrap(),
+            ("npub".into(), input.to_vec(), Variant::Bech32)
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(
+            bech32::decode_5bits(" 1nwldj5"),
+            Err(Bech32Error::InvalidHrpChar { ch: ' ', idx: 0 })
+        );
+        assert_eq!(
+            bech32::decode_5bits("pzry9x0s0muk"),
+            Err(Bech32Error::MissingSeparator)
+        );
+        assert_eq!(
+            bech32::decode_5bits("1pzry9x0s0muk"),
+            Err(Bech32Error::BadHrpLen(0))
+        );
+        assert_eq!(
+            bech32::decode_5bits("x1b4n0q5v"),
+            Err(Bech32Error::InvalidChar { ch: 'b', idx: 2 })
+        );
+        assert_eq!(
+            bech32::decode_5bits("li1dgmt3"),
+            Err(Bech32Error::MissingChecksum(5))
+        );
+        assert_eq!(
+            bech32::decode_5bits("A1G7SGD8"),
+            Err(Bech32Error::InvalidChecksum)
+        );
+        assert_eq!(
+            bech32::decode_5bits("a12UEL5L"),
+            Err(Bech32Error::MixedCase)
+        );
+    }
+
+    #[test]
+    fn test_bad_padding() {
+        // the data value 'l' = 31 leaves non zero padding bits
+        let input = bech32::encode_5bits("a", &[31], Variant::Bech32).unwrap();
+
+        assert_eq!(bech32::decode(&input), Err(Bech32Error::InvalidPadding));
+    }
+}
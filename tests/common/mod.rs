@@ -0,0 +1,15 @@
+// the fixtures that are shared by the integration tests
+
+// pseudo random bytes, the same for a given length across runs
+pub fn input(len: usize) -> Vec<u8> {
+    let mut state = len as u32 ^ 0x9e37_79b9;
+
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as u8
+        })
+        .collect()
+}
@@ -0,0 +1,161 @@
+// the constant time codecs are checked against the regular ones
+
+mod common;
+use common::input;
+
+mod encoder {
+    use super::input;
+    use makura::{Encoder, ct};
+
+    #[test]
+    fn test0() {
+        (0..100).for_each(|len| {
+            let value = input(len);
+
+            assert_eq!(
+                ct::base64_encode(&value),
+                Encoder::base64().encode_bytes(&value)
+            );
+            assert_eq!(
+                ct::base64_url_encode(&value),
+                Encoder::base64_url().encode_bytes(&value)
+            );
+            assert_eq!(
+                ct::base32_encode(&value),
+                Encoder::base32().encode_bytes(&value)
+            );
+            assert_eq!(
+                ct::base16_encode(&value),
+                Encoder::base16().encode_bytes(&value)
+            );
+            assert_eq!(
+                ct::base16_lower_encode(&value),
+                Encoder::base16().lowercase().encode_bytes(&value)
+            );
+        });
+    }
+
+    // every byte value goes through every table index
+    #[test]
+    fn test1() {
+        let value = (0..=255).chain((0..=255).rev()).collect::<Vec<u8>>();
+
+        assert_eq!(
+            ct::base64_encode(&value),
+            Encoder::base64().encode_bytes(&value)
+        );
+        assert_eq!(
+            ct::base64_url_encode(&value),
+            Encoder::base64_url().encode_bytes(&value)
+        );
+        assert_eq!(
+            ct::base32_encode(&value),
+            Encoder::base32().encode_bytes(&value)
+        );
+        assert_eq!(
+            ct::base16_encode(&value),
+            Encoder::base16().encode_bytes(&value)
+        );
+    }
+}
+
+mod decoder {
+    use super::input;
+    use makura::{DecodeError, Encoder, ct};
+
+    #[test]
+    fn test0() {
+        (0..100).for_each(|len| {
+            let value = input(len);
+
+            assert_eq!(
+                ct::base64_decode(Encoder::base64().encode_bytes(&value)).unwrap(),
+                value
+            );
+            assert_eq!(
+                ct::base64_url_decode(Encoder::base64_url().encode_bytes(&value)).unwrap(),
+                value
+            );
+            assert_eq!(
+                ct::base32_decode(Encoder::base32().encode_bytes(&value)).unwrap(),
+                value
+            );
+            assert_eq!(
+                ct::base16_decode(Encoder::base16().encode_bytes(&value)).unwrap(),
+                value
+            );
+        });
+    }
+
+    // unpadded and lowercase values
+    #[test]
+    fn test1() {
+        assert_eq!(ct::base64_decode("Zm9vYg").unwrap(), b"foob");
+        assert_eq!(ct::base64_url_decode("-_8").unwrap(), [0xfb, 0xff]);
+        assert_eq!(ct::base32_decode("mzxw6yq").unwrap(), b"foob");
+        assert_eq!(
+            ct::base16_decode("DeadBeef").unwrap(),
+            [0xde, 0xad, 0xbe, 0xef]
+        );
+    }
+
+    // every char that is not in the table is rejected
+    #[test]
+    fn test2() {
+        (0..=255u8).for_each(|b| {
+            let ch = b as char;
+            let value = format!("AA{ch}A");
+            let in_64 = ch.is_ascii_alphanumeric() || ch == '+' || ch == '/';
+            let in_64_url = ch.is_ascii_alphanumeric() || ch == '-' || ch == '_';
+
+            assert_eq!(ct::base64_decode(&value).is_ok(), in_64, "{ch:?}");
+            assert_eq!(ct::base64_url_decode(&value).is_ok(), in_64_url, "{ch:?}");
+
+            let value = format!("AAAA{ch}AAA");
+            let in_32 = ch.is_ascii_alphabetic() || ('2'..='7').contains(&ch);
+
+            assert_eq!(ct::base32_decode(&value).is_ok(), in_32, "{ch:?}");
+
+            let value = format!("A{ch}");
+
+            assert_eq!(
+                ct::base16_decode(&value).is_ok(),
+                ch.is_ascii_hexdigit(),
+                "{ch:?}"
+            );
+        });
+    }
+
+    #[test]
+    fn test3() {
+        assert!(matches!(
+            ct::base64_decode("Zm9vY"),
+            Err(DecodeError::BadLenForBase(5))
+        ));
+        assert!(matches!(
+            ct::base32_decode("MZX"),
+            Err(DecodeError::BadLenForBase(3))
+        ));
+        assert!(matches!(
+            ct::base16_decode("ABC"),
+            Err(DecodeError::BadLenForBase(3))
+        ));
+        assert!(matches!(
+            ct::base64_decode("Zm9v*g=="),
+            Err(DecodeError::BadEncodedString)
+        ));
+    }
+}
+
+mod eq {
+    use makura::ct;
+
+    #[test]
+    fn test0() {
+        assert!(ct::eq("Zm9vYg==", "Zm9vYg=="));
+        assert!(!ct::eq("Zm9vYg==", "Zm9vYh=="));
+        assert!(!ct::eq("Zm9vYg==", "Zm9vYg="));
+        assert!(ct::eq(b"", b""));
+        assert!(!ct::eq([0x00], [0x80]));
+    }
+}
@@ -0,0 +1,104 @@
+mod encoder {
+    use makura::data_url::{self, DataUrl};
+
+    #[test]
+    fn test0() {
+        let url = DataUrl::new("text/plain", "");
+        let output = "data:text/plain;base64,";
+
+        assert_eq!(data_url::encode(&url), output);
+    }
+
+    #[test]
+    fn test1() {
+        let url = DataUrl::new("text/plain", "Hello, World!").param("charset", "utf-8");
+        let output = "data:text/plain;charset=utf-8;base64,SGVsbG8sIFdvcmxkIQ==";
+
+        assert_eq!(data_url::encode(&url), output);
+    }
+
+    #[test]
+    fn test2() {
+        let url = DataUrl::new(
+            "image/png",
+            [0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a],
+        );
+        let output = "data:image/png;base64,iVBORw0KGgo=";
+
+        assert_eq!(data_url::encode(&url), output);
+    }
+}
+
+mod decoder {
+    use makura::data_url::{self, DataUrl, DataUrlError};
+
+    #[test]
+    fn test0() {
+        let input = "data:image/png;base64,iVBORw0KGgo=";
+        let output = DataUrl::new(
+            "image/png",
+            [0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a],
+        );
+
+        assert_eq!(data_url::decode(input).unwrap(), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = "data:,Hello%2C%20World%21";
+        let url = data_url::decode(input).unwrap();
+
+        assert_eq!(url.media_type, "text/plain");
+        assert_eq!(url.charset(), Some("US-ASCII"));
+        assert_eq!(url.data, b"Hello, World!");
+    }
+
+    #[test]
+    fn test2() {
+        let input = "DATA:Text/HTML;Charset=UTF-8,%3Ch1%3E%E2%9C%93%3C%2Fh1%3E";
+        let url = data_url::decode(input).unwrap();
+
+        assert_eq!(url.media_type, "text/html");
+        assert_eq!(url.charset(), Some("UTF-8"));
+        assert_eq!(url.data, "<h1>✓</h1>".as_bytes());
+    }
+
+    #[test]
+    fn test3() {
+        // unpadded base64, split over lines
+        let input = "data:text/plain;charset=utf-8;base64,SGVsbG8s\n IFdvcmxkIQ";
+        let url = data_url::decode(input).unwrap();
+
+        assert_eq!(url.charset(), Some("utf-8"));
+        assert_eq!(url.data, b"Hello, World!");
+    }
+
+    #[test]
+    fn test4() {
+        let input = "data:;base64,SGk%3D";
+        let url = data_url::decode(input).unwrap();
+
+        assert_eq!(url.media_type, "text/plain");
+        assert_eq!(url.data, b"Hi");
+    }
+
+    #[test]
+    fn test5() {
+        assert!(matches!(
+            data_url::decode("http://example.com"),
+            Err(DataUrlError::MissingScheme)
+        ));
+        assert!(matches!(
+            data_url::decode("data:text/plain"),
+            Err(DataUrlError::MissingComma)
+        ));
+        assert!(matches!(
+            data_url::decode("data:,100%"),
+            Err(DataUrlError::BadPercentEncoding(3))
+        ));
+        assert!(matches!(
+            data_url::decode("data:;base64,S!Gk"),
+            Err(DataUrlError::DecodeError(_))
+        ));
+    }
+}
@@ -0,0 +1,226 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::Write;
+
+// counts the allocations of the current thread, the tests run on their own threads
+struct Counter;
+
+thread_local! {
+    static ALLOCS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCS.with(|allocs| allocs.set(allocs.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static COUNTER: Counter = Counter;
+
+fn allocs() -> usize {
+    ALLOCS.with(|allocs| allocs.get())
+}
+
+// a fixed size fmt::Write that never allocates
+struct Buf {
+    bytes: [u8; 512],
+    len: usize,
+}
+
+impl Buf {
+    fn new() -> Self {
+        Self {
+            bytes: [0; 512],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+impl Write for Buf {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        if end > self.bytes.len() {
+            return Err(std::fmt::Error);
+        }
+        self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+
+        Ok(())
+    }
+}
+
+mod encoder {
+    use super::{Buf, allocs};
+    use makura::display::{Base16, Base32, Base64, Base64Url};
+    use makura::{BASE85Z, Encoder};
+    use std::fmt::Write;
+
+    #[test]
+    fn test0() {
+        let value = (0..=255).collect::<Vec<u8>>();
+        [
+            Encoder::base64(),
+            Encoder::base64_url(),
+            Encoder::base64_bcrypt(),
+            Encoder::base64_crypt(),
+            Encoder::base64_imap(),
+            Encoder::base64_xml_name(),
+            Encoder::base64_xml_nmtoken(),
+            Encoder::base64_y64(),
+            Encoder::base45(),
+            Encoder::base32(),
+            Encoder::base32_hex(),
+            Encoder::base32_nsec3(),
+            Encoder::base32_z(),
+            Encoder::base16(),
+            Encoder::base36(),
+            Encoder::base58(),
+            Encoder::base62(),
+            Encoder::base85(),
+            Encoder::base85_ascii(),
+            Encoder::base85_z(),
+            Encoder::base91(),
+        ]
+        .into_iter()
+        .for_each(|enc| {
+            // z85 only encodes whole chunks of 4 bytes
+            (0..value.len())
+                .step_by(17)
+                .filter(|len| *enc.base() != BASE85Z || len % 4 == 0)
+                .for_each(|len| {
+                    assert_eq!(
+                        enc.display(&value[..len]).to_string(),
+                        enc.encode_bytes(&value[..len]),
+                        "{:?} {len}",
+                        enc.base()
+                    )
+                })
+        });
+    }
+
+    #[test]
+    fn test1() {
+        let hash = [0xde, 0xad, 0xbe, 0xef];
+
+        assert_eq!(
+            format!("{:x}", Encoder::base16().display(&hash)),
+            "deadbeef"
+        );
+        assert_eq!(
+            format!("{:X}", Encoder::base16().display(&hash)),
+            "DEADBEEF"
+        );
+        assert_eq!(
+            format!("{}", Encoder::base16().lowercase().display(&hash)),
+            "deadbeef"
+        );
+        assert_eq!(
+            format!("{:X}", Encoder::base16().lowercase().display(&hash)),
+            "DEADBEEF"
+        );
+        assert_eq!(
+            format!("{:x}", Encoder::base32().display(&hash)),
+            "32w353y="
+        );
+    }
+
+    // the case selection has no effect on the case sensitive tables
+    #[test]
+    fn test2() {
+        let value = b"Hello";
+
+        assert_eq!(
+            format!("{:x}", Encoder::base64().display(value)),
+            Encoder::base64().encode_bytes(value)
+        );
+        assert_eq!(
+            format!("{:x}", Encoder::base58().display(value)),
+            Encoder::base58().encode_bytes(value)
+        );
+    }
+
+    #[test]
+    fn test3() {
+        let value = [0xfb, 0xff, 0x01];
+
+        assert_eq!(Base16(&value).to_string(), "FBFF01");
+        assert_eq!(format!("{:x}", Base16(&value)), "fbff01");
+        assert_eq!(Base32(&value).to_string(), "7P7QC===");
+        assert_eq!(Base64(&value).to_string(), "+/8B");
+        assert_eq!(Base64Url(&value).to_string(), "-_8B");
+        assert_eq!(
+            format!("{:?}", Encoder::base16().display(&value)),
+            "\"FBFF01\""
+        );
+    }
+
+    // the block bases write straight into the formatter
+    #[test]
+    fn test4() {
+        let value = [0x5a; 100];
+        let mut buf = Buf::new();
+        let before = allocs();
+        write!(
+            buf,
+            "{} {:x} {}",
+            Base64(&value),
+            Base16(&value[..20]),
+            Encoder::base85().display(&value[..40])
+        )
+        .unwrap();
+
+        assert_eq!(allocs(), before);
+        assert_eq!(
+            buf.as_str(),
+            format!(
+                "{} {} {}",
+                Encoder::base64().encode_bytes(value),
+                Encoder::base16().lowercase().encode_bytes(&value[..20]),
+                Encoder::base85().encode_bytes(&value[..40])
+            )
+        );
+    }
+
+    // a failed write is passed on
+    #[test]
+    fn test5() {
+        let mut buf = Buf::new();
+
+        assert!(write!(buf, "{}", Base16(&[0; 300])).is_err());
+    }
+
+    // z85 can't encode a partial group, the formatting fails before anything is written
+    #[test]
+    fn test6() {
+        let mut encoded = String::new();
+
+        assert!(write!(encoded, "{}", Encoder::base85_z().display(b"foobar")).is_err());
+        assert!(
+            Encoder::base85_z()
+                .encode_to_fmt(b"foo", &mut encoded)
+                .is_err()
+        );
+        assert_eq!(encoded, "");
+    }
+
+    // the nsec3 encoder is lowercase, its display still follows the format's case
+    #[test]
+    fn test7() {
+        let value = [0xfb, 0xff, 0x01];
+        let enc = Encoder::base32_nsec3();
+
+        assert_eq!(enc.display(&value).to_string(), "vfvg2");
+        assert_eq!(format!("{:x}", enc.display(&value)), "vfvg2");
+        assert_eq!(format!("{:X}", enc.display(&value)), "VFVG2");
+    }
+}
@@ -0,0 +1,118 @@
+mod encoder {
+    use makura::encoded_word::{self, WordEncoding};
+
+    #[test]
+    fn test0() {
+        assert_eq!(encoded_word::encode("", WordEncoding::B), "");
+    }
+
+    #[test]
+    fn test1() {
+        let input = "¡Hola!";
+        let output = "=?UTF-8?B?wqFIb2xhIQ==?=";
+
+        assert_eq!(encoded_word::encode(input, WordEncoding::B), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = "¡Hola, señor!";
+        let output = "=?UTF-8?Q?=C2=A1Hola=2C_se=C3=B1or!?=";
+
+        assert_eq!(encoded_word::encode(input, WordEncoding::Q), output);
+    }
+
+    #[test]
+    fn test3() {
+        let input = "ünïcödé ".repeat(10);
+        [WordEncoding::B, WordEncoding::Q]
+            .into_iter()
+            .for_each(|encoding| {
+                let output = encoded_word::encode(&input, encoding);
+
+                assert!(output.split("\r\n ").count() > 1);
+                assert!(output.split("\r\n ").all(|word| word.len() <= 75));
+                assert_eq!(encoded_word::decode(&output).unwrap(), input);
+            });
+    }
+}
+
+mod decoder {
+    use makura::encoded_word::{self, EncodedWordError};
+
+    #[test]
+    fn test0() {
+        let input = "=?UTF-8?B?wqFIb2xhIQ==?=";
+        let output = "¡Hola!";
+
+        assert_eq!(encoded_word::decode(input).unwrap(), output);
+    }
+
+    #[test]
+    fn test1() {
+        // rfc 2047 section 8 examples
+        let input = "=?ISO-8859-1?Q?Keld_J=F8rn_Simonsen?= <keld@dkuug.dk>";
+        let output = "Keld Jørn Simonsen <keld@dkuug.dk>";
+
+        assert_eq!(encoded_word::decode(input).unwrap(), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = "(=?ISO-8859-1?Q?a?= b)";
+        let output = "(a b)";
+
+        assert_eq!(encoded_word::decode(input).unwrap(), output);
+    }
+
+    #[test]
+    fn test3() {
+        // whitespace between adjacent encoded-words is dropped
+        let input = "(=?ISO-8859-1?Q?a?=\r\n    =?ISO-8859-1?Q?b?=)";
+        let output = "(ab)";
+
+        assert_eq!(encoded_word::decode(input).unwrap(), output);
+    }
+
+    #[test]
+    fn test4() {
+        // a char split between 2 words
+        let input = "=?utf-8?q?=C2?= =?UTF-8?Q?=A1Hola!?= amigo";
+        let output = "¡Hola! amigo";
+
+        assert_eq!(encoded_word::decode(input).unwrap(), output);
+    }
+
+    #[test]
+    fn test5() {
+        let input = "plain =?oops and =?UTF-8?B?aGk?=";
+        let output = "plain =?oops and hi";
+
+        assert_eq!(encoded_word::decode(input).unwrap(), output);
+    }
+
+    #[test]
+    fn test6() {
+        assert!(matches!(
+            encoded_word::decode("=?KOI8-R?B?aGk=?="),
+            Err(EncodedWordError::UnsupportedCharset(_))
+        ));
+        assert!(matches!(
+            encoded_word::decode("=?UTF-8?X?aGk=?="),
+            Err(EncodedWordError::UnknownEncoding(_))
+        ));
+        assert!(matches!(
+            encoded_word::decode("=?UTF-8?Q?=ZZ?="),
+            Err(EncodedWordError::BadQEncoding(_))
+        ));
+        // a sign is not a hex digit
+        assert!(matches!(
+            encoded_word::decode("=?UTF-8?Q?=+F?="),
+            Err(EncodedWordError::BadQEncoding(_))
+        ));
+        assert!(matches!(
+            encoded_word::decode("=?UTF-8?B?a!k=?="),
+            Err(EncodedWordError::DecodeError(_))
+        ));
+    }
+}
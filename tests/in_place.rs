@@ -0,0 +1,119 @@
+mod common;
+use common::input;
+
+mod decoder {
+    use super::input;
+    use makura::{
+        BASE16, BASE32, BASE45, BASE58, BASE64, BASE85, BASE85ASCII, BASE85Z, DecodeError, Decoder,
+        Encoder,
+    };
+
+    #[test]
+    fn test0() {
+        [
+            Encoder::base64(),
+            Encoder::base64_url(),
+            Encoder::base64_bcrypt(),
+            Encoder::base64_crypt(),
+            Encoder::base64_imap(),
+            Encoder::base64_xml_name(),
+            Encoder::base64_xml_nmtoken(),
+            Encoder::base64_y64(),
+            Encoder::base45(),
+            Encoder::base32(),
+            Encoder::base32_hex(),
+            Encoder::base32_nsec3(),
+            Encoder::base32_z(),
+            Encoder::base16(),
+            Encoder::base85(),
+            Encoder::base85_z(),
+        ]
+        .into_iter()
+        .for_each(|enc| {
+            // z85 only encodes whole chunks of 4 bytes
+            (0..40)
+                .filter(|len| *enc.base() != BASE85Z || len % 4 == 0)
+                .for_each(|len| {
+                    let value = input(len);
+                    let mut encoded = enc.encode_bytes(&value).into_bytes();
+
+                    assert_eq!(
+                        Decoder::decode_in_place(&mut encoded, *enc.base()).unwrap(),
+                        value,
+                        "{:?} {len}",
+                        enc.base()
+                    );
+                })
+        });
+    }
+
+    // the encoded value is only part of a larger request buffer
+    #[test]
+    fn test1() {
+        let mut request = *b"GET /?token=Zm9vYmFy HTTP/1.1";
+        let token = Decoder::decode_in_place(&mut request[12..20], BASE64).unwrap();
+
+        assert_eq!(token, b"foobar");
+
+        let mut hash = *b"deadBEEF";
+
+        assert_eq!(
+            Decoder::decode_in_place(&mut hash, BASE16).unwrap(),
+            [0xde, 0xad, 0xbe, 0xef]
+        );
+    }
+
+    #[test]
+    fn test2() {
+        assert!(matches!(
+            Decoder::decode_in_place(&mut b"Zm9v*g==".to_owned(), BASE64),
+            Err(DecodeError::BadEncodedString)
+        ));
+        // padding in the middle of the value
+        assert!(matches!(
+            Decoder::decode_in_place(&mut b"Zg==Zg==".to_owned(), BASE64),
+            Err(DecodeError::BadEncodedString)
+        ));
+        assert!(matches!(
+            Decoder::decode_in_place(&mut b"MZXW\xc36Q=".to_owned(), BASE32),
+            Err(DecodeError::BadEncodedString)
+        ));
+    }
+
+    #[test]
+    fn test3() {
+        assert!(matches!(
+            Decoder::decode_in_place(&mut b"!!!!!~~~~~".to_owned(), BASE85),
+            Err(DecodeError::Base85GroupOverflow(1))
+        ));
+        assert!(matches!(
+            Decoder::decode_in_place(&mut b"!!!!!!".to_owned(), BASE85),
+            Err(DecodeError::BadLenForBase(6))
+        ));
+        assert!(matches!(
+            Decoder::decode_in_place(&mut b"A".to_owned(), BASE16),
+            Err(DecodeError::BadLenForBase(1))
+        ));
+        assert!(matches!(
+            Decoder::decode_in_place(&mut b"Zm9vY===".to_owned(), BASE64),
+            Err(DecodeError::BadLenForBase(5))
+        ));
+        assert!(matches!(
+            Decoder::decode_in_place(&mut b"BB8ZZZ".to_owned(), BASE45),
+            Err(DecodeError::Base45GroupOverflow(1))
+        ));
+    }
+
+    // the bases without blocks can't be decoded in place
+    #[test]
+    fn test4() {
+        assert!(matches!(
+            Decoder::decode_in_place(&mut b"2NEpo7TZRRrLZSi2U".to_owned(), BASE58),
+            Err(DecodeError::UnsupportedForBase(BASE58))
+        ));
+        assert!(matches!(
+            Decoder::decode_in_place(&mut b"<~z~>".to_owned(), BASE85ASCII),
+            Err(DecodeError::UnsupportedForBase(BASE85ASCII))
+        ));
+    }
+}
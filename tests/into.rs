@@ -0,0 +1,188 @@
+mod encoder {
+    use makura::Encoder;
+    use std::fmt::{self, Write};
+
+    #[test]
+    fn test0() {
+        let mut body = String::from("{\"id\":\"");
+        Encoder::base64_url().encode_into([0xfb, 0xff], &mut body);
+        body.push_str("\",\"hash\":\"");
+        Encoder::base16()
+            .lowercase()
+            .encode_into([0xde, 0xad], &mut body);
+        body.push_str("\"}");
+
+        assert_eq!(body, "{\"id\":\"-_8=\",\"hash\":\"dead\"}");
+    }
+
+    // the existing capacity is reused
+    #[test]
+    fn test1() {
+        let mut encoded = String::with_capacity(1024);
+        let ptr = encoded.as_ptr();
+        (0..10).for_each(|_| {
+            encoded.clear();
+            Encoder::base64().encode_into([0x5a; 300], &mut encoded);
+        });
+
+        assert_eq!(encoded.as_ptr(), ptr);
+        assert_eq!(encoded, Encoder::base64().encode_bytes([0x5a; 300]));
+    }
+
+    #[test]
+    fn test2() {
+        let mut encoded = String::from("hash=");
+        Encoder::base32_hex()
+            .lowercase()
+            .encode_to_fmt(b"foobar", &mut encoded)
+            .unwrap();
+
+        assert_eq!(encoded, "hash=cpnmuoj1e8======");
+    }
+
+    // a Formatter is a fmt::Write as well
+    #[test]
+    fn test3() {
+        struct Token<'a>(&'a [u8]);
+
+        impl fmt::Display for Token<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("Bearer ")?;
+                Encoder::base64().encode_to_fmt(self.0, f)
+            }
+        }
+
+        assert_eq!(Token(b"foob").to_string(), "Bearer Zm9vYg==");
+    }
+
+    // every base writes the same chars into a fmt::Write as into a String
+    #[test]
+    fn test4() {
+        let value = (0..=255).rev().collect::<Vec<u8>>();
+        [
+            Encoder::base64(),
+            Encoder::base64_y64(),
+            Encoder::base45(),
+            Encoder::base32_nsec3(),
+            Encoder::base32_z(),
+            Encoder::base16().lowercase(),
+            Encoder::base58(),
+            Encoder::base85_ascii(),
+            Encoder::base91(),
+        ]
+        .into_iter()
+        .for_each(|enc| {
+            let mut encoded = String::new();
+            write!(encoded, "-").unwrap();
+            enc.encode_to_fmt(&value, &mut encoded).unwrap();
+
+            assert_eq!(encoded, format!("-{}", enc.encode_bytes(&value)));
+        });
+    }
+}
+
+mod decoder {
+    use makura::{BASE16, BASE32, BASE45, BASE64, BASE85, DecodeError, Decoder};
+
+    #[test]
+    fn test0() {
+        let mut body = b"key=".to_vec();
+        Decoder::decode_into("Zm9vYg==", BASE64, &mut body).unwrap();
+        body.push(b'&');
+        Decoder::decode_into("DEAD", BASE16, &mut body).unwrap();
+
+        assert_eq!(body, b"key=foob&\xde\xad");
+    }
+
+    // the existing content is left untouched by an error
+    #[test]
+    fn test1() {
+        let mut body = b"key=".to_vec();
+
+        assert!(matches!(
+            Decoder::decode_into("Zm9v*g==", BASE64, &mut body),
+            Err(DecodeError::BadEncodedString)
+        ));
+        assert_eq!(body, b"key=");
+
+        // the first group decodes fine before the second one overflows
+        assert!(matches!(
+            Decoder::decode_into("!!!!!~~~~~", BASE85, &mut body),
+            Err(DecodeError::Base85GroupOverflow(1))
+        ));
+        assert_eq!(body, b"key=");
+    }
+
+    // the existing capacity is reused
+    #[test]
+    fn test2() {
+        let encoded = makura::Encoder::base64().encode_bytes([0x5a; 300]);
+        let mut decoded = Vec::with_capacity(1024);
+        let ptr = decoded.as_ptr();
+        (0..10).for_each(|_| {
+            decoded.clear();
+            Decoder::decode_into(&encoded, BASE64, &mut decoded).unwrap();
+        });
+
+        assert_eq!(decoded.as_ptr(), ptr);
+        assert_eq!(decoded, [0x5a; 300]);
+    }
+
+    // a bad char that comes after the simd blocks
+    #[test]
+    fn test3() {
+        let mut encoded = "A".repeat(256);
+        encoded.push_str("Zm9*");
+        let mut decoded = vec![1, 2, 3];
+
+        assert!(Decoder::decode_into(&encoded, BASE64, &mut decoded).is_err());
+        assert_eq!(decoded, [1, 2, 3]);
+    }
+
+    // the lengths that can't hold whole bytes are rejected, not decoded into garbage
+    #[test]
+    fn test4() {
+        [
+            ("A", BASE16, 1),
+            ("ABC", BASE16, 3),
+            ("Z", BASE64, 1),
+            ("Zm9vY===", BASE64, 5),
+            ("M", BASE32, 1),
+            ("MZX=====", BASE32, 3),
+            ("MZXW6Y==", BASE32, 6),
+            ("A", BASE45, 1),
+            ("BB8A", BASE45, 4),
+        ]
+        .into_iter()
+        .for_each(|(value, base, len)| {
+            assert!(
+                matches!(
+                    Decoder::decode_into(value, base, &mut Vec::new()),
+                    Err(DecodeError::BadLenForBase(l)) if l == len
+                ),
+                "{value:?} {base:?}"
+            )
+        });
+
+        // the length is that of the whole value, the simd blocks included
+        let encoded = "AB".repeat(64) + "A";
+
+        assert!(matches!(
+            Decoder::decode_into(&encoded, BASE16, &mut Vec::new()),
+            Err(DecodeError::BadLenForBase(129))
+        ));
+    }
+
+    // a base 45 group can't hold more than its 2 bytes, nor a final group more than 1 byte
+    #[test]
+    fn test5() {
+        assert!(matches!(
+            Decoder::decode_into("ZZZ", BASE45, &mut Vec::new()),
+            Err(DecodeError::Base45GroupOverflow(0))
+        ));
+        assert!(matches!(
+            Decoder::decode_into("BB8ZZ", BASE45, &mut Vec::new()),
+            Err(DecodeError::Base45GroupOverflow(1))
+        ));
+    }
+}
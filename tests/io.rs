@@ -0,0 +1,287 @@
+#![cfg(all(feature = "tokio", feature = "futures-io"))]
+
+use makura::Encoder;
+
+mod common;
+use common::input;
+
+fn encoders() -> [Encoder; 9] {
+    [
+        Encoder::base64(),
+        Encoder::base64_y64(),
+        Encoder::base45(),
+        Encoder::base32_nsec3(),
+        Encoder::base16().lowercase(),
+        Encoder::base58(),
+        Encoder::base85(),
+        Encoder::base85_ascii(),
+        Encoder::base91(),
+    ]
+}
+
+mod encoder {
+    use super::{encoders, input};
+    use makura::Encoder;
+    use makura::io::EncodeWriter;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    // the small duplex buffer makes the writes wait on the reader
+    #[tokio::test]
+    async fn test0() {
+        for enc in encoders() {
+            for len in [0, 1, 2, 5, 17, 300, 1000] {
+                let value = input(len);
+                let (writer, mut reader) = tokio::io::duplex(16);
+                let mut writer = EncodeWriter::new(writer, enc);
+                let write = async {
+                    for chunk in value.chunks(7) {
+                        writer.write_all(chunk).await.unwrap();
+                    }
+                    writer.shutdown().await.unwrap();
+                };
+                let mut encoded = String::new();
+                let read = reader.read_to_string(&mut encoded);
+                let (_, read) = tokio::join!(write, read);
+                read.unwrap();
+
+                assert_eq!(encoded, enc.encode_bytes(&value), "{:?} {len}", enc.base());
+            }
+        }
+    }
+
+    // flushing doesn't write a partial block
+    #[tokio::test]
+    async fn test1() {
+        let mut writer = EncodeWriter::new(Vec::new(), Encoder::base64());
+        writer.write_all(b"foob").await.unwrap();
+        writer.flush().await.unwrap();
+
+        assert_eq!(writer.get_ref(), b"Zm9v");
+
+        writer.shutdown().await.unwrap();
+
+        assert_eq!(writer.into_inner(), b"Zm9vYg==");
+    }
+
+    #[test]
+    fn test2() {
+        use futures::io::AsyncWriteExt as _;
+
+        futures::executor::block_on(async {
+            let value = input(1000);
+            let mut writer = EncodeWriter::new(Vec::new(), Encoder::base32());
+            for chunk in value.chunks(13) {
+                futures::io::AsyncWriteExt::write_all(&mut writer, chunk)
+                    .await
+                    .unwrap();
+            }
+            writer.close().await.unwrap();
+
+            assert_eq!(
+                writer.get_ref(),
+                Encoder::base32().encode_bytes(&value).as_bytes()
+            );
+        });
+    }
+
+    // z85 can't encode a partial group, shutting the writer down fails
+    #[tokio::test]
+    async fn test3() {
+        let mut writer = EncodeWriter::new(Vec::new(), Encoder::base85_z());
+        writer.write_all(b"foobar").await.unwrap();
+
+        assert_eq!(
+            writer.shutdown().await.unwrap_err().kind(),
+            std::io::ErrorKind::InvalidInput
+        );
+        assert_eq!(writer.get_ref(), b"w]zP%");
+    }
+}
+
+mod decoder {
+    use super::{encoders, input};
+    use makura::io::DecodeReader;
+    use makura::{BASE64, BASE85, DecodeError, Encoder};
+    use std::io;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    fn decode_error(err: &io::Error) -> &DecodeError {
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        err.get_ref().unwrap().downcast_ref().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test0() {
+        for enc in encoders() {
+            for len in [0, 1, 2, 5, 17, 300, 1000] {
+                let value = input(len);
+                let encoded = enc.encode_bytes(&value);
+                let (mut writer, reader) = tokio::io::duplex(16);
+                let write = async {
+                    for chunk in encoded.as_bytes().chunks(7) {
+                        writer.write_all(chunk).await.unwrap();
+                    }
+                    writer.shutdown().await.unwrap();
+                };
+                let mut reader = DecodeReader::new(reader, *enc.base());
+                let mut decoded = Vec::new();
+                let read = reader.read_to_end(&mut decoded);
+                let (_, read) = tokio::join!(write, read);
+                read.unwrap();
+
+                assert_eq!(decoded, value, "{:?} {len}", enc.base());
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test1() {
+        // padding in the middle of the value
+        let mut reader = DecodeReader::new(&b"Zg==Zm9v"[..], BASE64);
+        let err = reader.read_to_end(&mut Vec::new()).await.unwrap_err();
+
+        assert!(matches!(decode_error(&err), DecodeError::BadEncodedString));
+
+        // the length covers the whole value, not only the last chunk
+        let mut encoded = Encoder::base85().encode_bytes(input(1000));
+        encoded.truncate(encoded.len() - 4);
+        let mut reader = DecodeReader::new(encoded.as_bytes(), BASE85);
+        let err = reader.read_to_end(&mut Vec::new()).await.unwrap_err();
+
+        assert!(matches!(
+            decode_error(&err),
+            DecodeError::BadLenForBase(1246)
+        ));
+
+        // the reader ends after an error
+        let mut decoded = Vec::new();
+        reader.read_to_end(&mut decoded).await.unwrap();
+
+        assert!(decoded.is_empty());
+    }
+
+    // a bad char after a few whole chunks
+    #[tokio::test]
+    async fn test2() {
+        let mut encoded = Encoder::base64().encode_bytes(input(3000));
+        encoded.replace_range(3000..3001, "*");
+        let mut reader = DecodeReader::new(encoded.as_bytes(), BASE64);
+        let mut decoded = Vec::new();
+        let err = reader.read_to_end(&mut decoded).await.unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(decoded.len() < 2250);
+        assert_eq!(decoded, input(3000)[..decoded.len()]);
+    }
+
+    #[test]
+    fn test3() {
+        futures::executor::block_on(async {
+            let value = input(1000);
+            let encoded = Encoder::base32().encode_bytes(&value);
+            let mut reader = DecodeReader::new(encoded.as_bytes(), makura::BASE32);
+            let mut decoded = Vec::new();
+            futures::io::AsyncReadExt::read_to_end(&mut reader, &mut decoded)
+                .await
+                .unwrap();
+
+            assert_eq!(decoded, value);
+        });
+    }
+}
+
+mod codec {
+    use super::input;
+    use futures::{SinkExt, StreamExt};
+    use makura::Encoder;
+    use makura::io::codec::FramesCodec;
+    use std::io;
+    use tokio::io::AsyncWriteExt;
+    use tokio_util::codec::{FramedRead, FramedWrite};
+
+    #[tokio::test]
+    async fn test0() {
+        let messages = (0..50).map(|len| input(len * 7)).collect::<Vec<_>>();
+        let (writer, reader) = tokio::io::duplex(64);
+        let mut sink = FramedWrite::new(writer, FramesCodec::default());
+        let write = async {
+            for message in &messages {
+                sink.send(message).await.unwrap();
+            }
+            sink.get_mut().shutdown().await.unwrap();
+        };
+        let read = FramedRead::new(reader, FramesCodec::default()).collect::<Vec<_>>();
+        let (_, frames) = tokio::join!(write, read);
+
+        assert_eq!(
+            frames.into_iter().collect::<Result<Vec<_>, _>>().unwrap(),
+            messages
+        );
+    }
+
+    // "\r\n" line endings and a last frame without a newline
+    #[tokio::test]
+    async fn test1() {
+        let (mut writer, reader) = tokio::io::duplex(64);
+        writer.write_all(b"Zm9vYg==\r\n\nYmFy").await.unwrap();
+        drop(writer);
+        let frames = FramedRead::new(reader, FramesCodec::default())
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(
+            frames.into_iter().collect::<Result<Vec<_>, _>>().unwrap(),
+            [b"foob".to_vec(), vec![], b"bar".to_vec()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test2() {
+        let codec = FramesCodec::new(Encoder::base32_hex().lowercase());
+        let (writer, reader) = tokio::io::duplex(64);
+        let mut sink = FramedWrite::new(writer, codec);
+        sink.send(b"foobar").await.unwrap();
+        drop(sink);
+        let mut frames = FramedRead::new(reader, FramesCodec::new(Encoder::base32_hex()));
+
+        assert_eq!(frames.next().await.unwrap().unwrap(), b"foobar");
+        assert!(frames.next().await.is_none());
+    }
+
+    // a frame that is too long or badly encoded fails the stream
+    #[tokio::test]
+    async fn test3() {
+        let (mut writer, reader) = tokio::io::duplex(256);
+        writer.write_all(b"Zm9vYg==\n").await.unwrap();
+        writer.write_all("A".repeat(100).as_bytes()).await.unwrap();
+        drop(writer);
+        let mut frames = FramedRead::new(reader, FramesCodec::default().with_max_len(8));
+
+        assert_eq!(frames.next().await.unwrap().unwrap(), b"foob");
+        assert_eq!(
+            frames.next().await.unwrap().unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+
+        let (mut writer, reader) = tokio::io::duplex(64);
+        writer.write_all(b"Zm9v*g==\n").await.unwrap();
+        drop(writer);
+        let mut frames = FramedRead::new(reader, FramesCodec::default());
+
+        assert_eq!(
+            frames.next().await.unwrap().unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[tokio::test]
+    async fn test4() {
+        let (writer, _reader) = tokio::io::duplex(64);
+        let mut sink = FramedWrite::new(writer, FramesCodec::new(Encoder::base85_z()));
+
+        assert_eq!(
+            sink.send(b"foobar").await.unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+}
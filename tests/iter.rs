@@ -0,0 +1,175 @@
+// the block bases read their input a few blocks at a time,
+// the inputs here are a few such chunks long, with a partial last chunk
+
+mod common;
+use common::input;
+
+// the encoders that can encode a value of len bytes,
+// z85 only encodes whole chunks of 4 bytes
+fn encoders(len: usize) -> impl Iterator<Item = makura::Encoder> {
+    use makura::{BASE85Z, Encoder};
+
+    [
+        Encoder::base64(),
+        Encoder::base64_url(),
+        Encoder::base64_bcrypt(),
+        Encoder::base64_crypt(),
+        Encoder::base64_imap(),
+        Encoder::base64_xml_name(),
+        Encoder::base64_xml_nmtoken(),
+        Encoder::base64_y64(),
+        Encoder::base45(),
+        Encoder::base32(),
+        Encoder::base32_hex(),
+        Encoder::base32_nsec3(),
+        Encoder::base32_z(),
+        Encoder::base16(),
+        Encoder::base36(),
+        Encoder::base58(),
+        Encoder::base62(),
+        Encoder::base85(),
+        Encoder::base85_ascii(),
+        Encoder::base85_z(),
+        Encoder::base91(),
+    ]
+    .into_iter()
+    .filter(move |enc| *enc.base() != BASE85Z || len % 4 == 0)
+}
+
+mod encoder {
+    use super::{encoders, input};
+    use makura::Encoder;
+
+    #[test]
+    fn test0() {
+        [0, 1, 2, 3, 4, 5, 191, 192, 193, 1000]
+            .into_iter()
+            .for_each(|len| {
+                let value = input(len);
+                encoders(len).for_each(|enc| {
+                    assert_eq!(
+                        enc.encode_iter(value.iter().copied()).collect::<String>(),
+                        enc.encode_bytes(&value),
+                        "{:?} {len}",
+                        enc.base()
+                    )
+                });
+            });
+    }
+
+    #[test]
+    fn test1() {
+        let value = input(1000);
+        let enc = Encoder::base16().lowercase();
+
+        assert_eq!(
+            enc.encode_iter(value.iter().copied()).collect::<String>(),
+            enc.encode_bytes(&value)
+        );
+    }
+
+    // the input is only read as the output is consumed
+    #[test]
+    fn test2() {
+        let mut read = 0;
+        let value = core::iter::repeat(0xff).inspect(|_| read += 1);
+        let encoded = Encoder::base64()
+            .encode_iter(value)
+            .take(4)
+            .collect::<String>();
+
+        assert_eq!(encoded, "////");
+        assert!(read < 1000);
+    }
+
+    // z85 can't encode a partial group, the iterator ends before it
+    #[test]
+    fn test3() {
+        let value = input(1001);
+        let mut encoded = Encoder::base85_z().encode_iter(value.iter().copied());
+
+        assert_eq!(
+            encoded.by_ref().collect::<String>(),
+            Encoder::base85_z().encode_bytes(&value[..1000])
+        );
+        assert!(matches!(
+            encoded.error(),
+            Err(makura::DecodeError::BadLenForBase(1001))
+        ));
+    }
+}
+
+mod decoder {
+    use super::{encoders, input};
+    use makura::{BASE32, BASE64, BASE85, DecodeError, Decoder};
+
+    #[test]
+    fn test0() {
+        [0, 1, 2, 3, 4, 5, 191, 192, 193, 1000]
+            .into_iter()
+            .for_each(|len| {
+                let value = input(len);
+                encoders(len).for_each(|enc| {
+                    let encoded = enc.encode_bytes(&value);
+
+                    assert_eq!(
+                        Decoder::decode_iter(encoded.chars(), *enc.base())
+                            .collect::<Result<Vec<u8>, DecodeError>>()
+                            .unwrap(),
+                        value,
+                        "{:?} {len}",
+                        enc.base()
+                    )
+                });
+            });
+    }
+
+    // a bad char yields a single error, then the iterator ends
+    #[test]
+    fn test1() {
+        let mut encoded = makura::Encoder::base64().encode_bytes(input(1000));
+        encoded.replace_range(500..501, "*");
+        let decoded = Decoder::decode_iter(encoded.chars(), BASE64).collect::<Vec<_>>();
+
+        assert!(matches!(
+            decoded.last(),
+            Some(Err(DecodeError::BadEncodedString))
+        ));
+        assert_eq!(decoded.iter().filter(|res| res.is_err()).count(), 1);
+    }
+
+    // padding in the middle of the value
+    #[test]
+    fn test2() {
+        let encoded = "Zg==".repeat(100);
+
+        assert!(
+            Decoder::decode_iter(encoded.chars(), BASE32)
+                .any(|res| matches!(res, Err(DecodeError::BadEncodedString)))
+        );
+        assert!(
+            Decoder::decode_iter(encoded.chars(), BASE64)
+                .any(|res| matches!(res, Err(DecodeError::BadEncodedString)))
+        );
+    }
+
+    // the positions that the errors hold are relative to the whole value
+    #[test]
+    fn test3() {
+        let mut encoded = makura::Encoder::base85().encode_bytes(input(1000));
+        encoded.replace_range(900..905, "~~~~~");
+
+        assert!(matches!(
+            Decoder::decode_iter(encoded.chars(), BASE85).last(),
+            Some(Err(DecodeError::Base85GroupOverflow(180)))
+        ));
+
+        let mut encoded = makura::Encoder::base85().encode_bytes(input(1000));
+        encoded.truncate(encoded.len() - 4);
+
+        assert!(matches!(
+            Decoder::decode_iter(encoded.chars(), BASE85).last(),
+            Some(Err(DecodeError::BadLenForBase(1246)))
+        ));
+    }
+}
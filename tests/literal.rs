@@ -0,0 +1,70 @@
+mod encoder {
+    use makura::Encoder;
+
+    const INCLUDED: &str = makura::include_base64!("data/literal.bin");
+
+    #[test]
+    fn test0() {
+        let file = std::fs::read("tests/data/literal.bin").unwrap();
+
+        assert_eq!(INCLUDED, Encoder::base64().encode_bytes(file));
+    }
+
+    #[test]
+    fn test1() {
+        const EMPTY: [u8; 0] = makura::literal::base64_encode(b"");
+        const F: [u8; 4] = makura::literal::base64_encode(b"f");
+        const FOOBA: [u8; 8] = makura::literal::base64_encode(b"fooba");
+
+        assert_eq!(EMPTY, *b"");
+        assert_eq!(F, *b"Zg==");
+        assert_eq!(FOOBA, *b"Zm9vYmE=");
+    }
+}
+
+mod decoder {
+    #[test]
+    fn test0() {
+        const KEY: [u8; 4] = makura::hex!("deadBEEF");
+
+        assert_eq!(KEY, [0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[test]
+    fn test1() {
+        assert_eq!(makura::hex!(""), []);
+        assert_eq!(makura::hex!("00ff"), [0, 255]);
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(makura::base64!("Zm9vYmFy"), *b"foobar");
+        assert_eq!(makura::base64!("Zm9vYmE="), *b"fooba");
+        assert_eq!(makura::base64!("Zm9vYg=="), *b"foob");
+    }
+
+    // unpadded literals
+    #[test]
+    fn test3() {
+        assert_eq!(makura::base64!("Zm9vYmE"), *b"fooba");
+        assert_eq!(makura::base64!("AA"), [0]);
+    }
+
+    #[test]
+    fn test4() {
+        const BYTES: [u8; 3] = makura::literal::base64("/+8A");
+
+        assert_eq!(BYTES, [0xff, 0xef, 0x00]);
+    }
+
+    // more padding than the final partial group needs
+    #[test]
+    fn test5() {
+        for value in ["Zm9v====", "Zm9v=", "Zg===", "Zm9vYg=", "Zm9vYmE=="] {
+            assert!(
+                std::panic::catch_unwind(|| makura::literal::base64_len(value)).is_err(),
+                "{value}"
+            );
+        }
+    }
+}
@@ -0,0 +1,195 @@
+mod encoder {
+    use makura::multibase::{self, MultibaseError};
+    use makura::{BASE16, BASE32, BASE32HEX, BASE32Z, BASE36, BASE58, BASE62, BASE64, BASE64URL};
+
+    const INPUT: &str = "yes mani !";
+
+    #[test]
+    fn test0() {
+        let output = "f796573206d616e692021";
+
+        assert_eq!(multibase::encode(BASE16, INPUT).unwrap(), output);
+    }
+
+    #[test]
+    fn test1() {
+        let output = "bpfsxgidnmfxgsibb";
+
+        assert_eq!(multibase::encode(BASE32, INPUT).unwrap(), output);
+    }
+
+    #[test]
+    fn test2() {
+        let output = "vf5in683dc5n6i811";
+
+        assert_eq!(multibase::encode(BASE32HEX, INPUT).unwrap(), output);
+    }
+
+    #[test]
+    fn test3() {
+        let output = "hxf1zgedpcfzg1ebb";
+
+        assert_eq!(multibase::encode(BASE32Z, INPUT).unwrap(), output);
+    }
+
+    #[test]
+    fn test4() {
+        let output = "k2lcpzo5yikidynfl";
+
+        assert_eq!(multibase::encode(BASE36, INPUT).unwrap(), output);
+    }
+
+    #[test]
+    fn test5() {
+        let output = "z7paNL19xttacUY";
+
+        assert_eq!(multibase::encode(BASE58, INPUT).unwrap(), output);
+    }
+
+    #[test]
+    fn test6() {
+        let output = "meWVzIG1hbmkgIQ";
+
+        assert_eq!(multibase::encode(BASE64, INPUT).unwrap(), output);
+    }
+
+    #[test]
+    fn test7() {
+        let output = "ueWVzIG1hbmkgIQ";
+
+        assert_eq!(multibase::encode(BASE64URL, INPUT).unwrap(), output);
+    }
+
+    #[test]
+    fn test8() {
+        assert!(matches!(
+            multibase::encode(BASE62, INPUT),
+            Err(MultibaseError::UnsupportedBase(BASE62))
+        ));
+    }
+}
+
+mod decoder {
+    use makura::multibase::{self, MultibaseError};
+    use makura::{BASE16, BASE32, BASE32HEX, BASE32Z, BASE36, BASE45, BASE58, BASE64, BASE64URL};
+
+    const OUTPUT: &[u8] = b"yes mani !";
+
+    #[test]
+    fn test0() {
+        let input = "F796573206D616E692021";
+
+        assert_eq!(multibase::decode(input).unwrap(), (BASE16, OUTPUT.to_vec()));
+    }
+
+    #[test]
+    fn test1() {
+        let input = "BPFSXGIDNMFXGSIBB";
+
+        assert_eq!(multibase::decode(input).unwrap(), (BASE32, OUTPUT.to_vec()));
+    }
+
+    #[test]
+    fn test2() {
+        let input = "cpfsxgidnmfxgsibb";
+
+        assert_eq!(multibase::decode(input).unwrap(), (BASE32, OUTPUT.to_vec()));
+    }
+
+    #[test]
+    fn test3() {
+        let input = "tf5in683dc5n6i811";
+
+        assert_eq!(
+            multibase::decode(input).unwrap(),
+            (BASE32HEX, OUTPUT.to_vec())
+        );
+    }
+
+    #[test]
+    fn test4() {
+        let input = "hxf1zgedpcfzg1ebb";
+
+        assert_eq!(
+            multibase::decode(input).unwrap(),
+            (BASE32Z, OUTPUT.to_vec())
+        );
+    }
+
+    #[test]
+    fn test5() {
+        let input = "K2LCPZO5YIKIDYNFL";
+
+        assert_eq!(multibase::decode(input).unwrap(), (BASE36, OUTPUT.to_vec()));
+    }
+
+    #[test]
+    fn test6() {
+        let input = "z7paNL19xttacUY";
+
+        assert_eq!(multibase::decode(input).unwrap(), (BASE58, OUTPUT.to_vec()));
+    }
+
+    #[test]
+    fn test7() {
+        let input = "MeWVzIG1hbmkgIQ==";
+
+        assert_eq!(multibase::decode(input).unwrap(), (BASE64, OUTPUT.to_vec()));
+    }
+
+    #[test]
+    fn test8() {
+        let input = "ueWVzIG1hbmkgIQ";
+
+        assert_eq!(
+            multibase::decode(input).unwrap(),
+            (BASE64URL, OUTPUT.to_vec())
+        );
+    }
+
+    #[test]
+    fn test9() {
+        let input = multibase::encode(BASE45, OUTPUT).unwrap();
+
+        assert!(input.starts_with('R'));
+        assert_eq!(
+            multibase::decode(&input).unwrap(),
+            (BASE45, OUTPUT.to_vec())
+        );
+    }
+
+    #[test]
+    fn test10() {
+        assert!(matches!(
+            multibase::decode(""),
+            Err(MultibaseError::MissingPrefix)
+        ));
+        assert!(matches!(
+            multibase::decode("Qabc"),
+            Err(MultibaseError::UnknownPrefix('Q'))
+        ));
+        assert!(matches!(
+            multibase::decode("z0OIl"),
+            Err(MultibaseError::DecodeError(_))
+        ));
+    }
+
+    // an unpadded prefix can't be followed by a padded payload
+    #[test]
+    fn test11() {
+        assert!(matches!(
+            multibase::decode("mZm9vYg=="),
+            Err(MultibaseError::DecodeError(
+                makura::DecodeError::BaseEncodingHasNoPaddingChars(BASE64)
+            ))
+        ));
+        assert!(matches!(
+            multibase::decode("bmzxw6==="),
+            Err(MultibaseError::DecodeError(_))
+        ));
+        assert_eq!(
+            multibase::decode("MZm9vYg==").unwrap(),
+            (BASE64, b"foob".to_vec())
+        );
+    }
+}
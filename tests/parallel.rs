@@ -0,0 +1,108 @@
+#![cfg(feature = "rayon")]
+// the inputs are a few parallel chunks long, with a partial last chunk
+
+mod common;
+use common::input;
+
+const LEN: usize = 300_001;
+
+mod encoder {
+    use super::{LEN, input};
+    use makura::Encoder;
+
+    #[test]
+    fn test0() {
+        let value = input(LEN);
+        [
+            Encoder::base64(),
+            Encoder::base64_url(),
+            Encoder::base64_y64(),
+            Encoder::base32(),
+            Encoder::base32_hex(),
+            Encoder::base32_z(),
+            Encoder::base16(),
+            Encoder::base45(),
+            Encoder::base85(),
+        ]
+        .into_iter()
+        .for_each(|enc| assert_eq!(enc.encode_par(&value), enc.encode_bytes(&value)));
+    }
+
+    #[test]
+    fn test1() {
+        let value = input(LEN);
+        let enc = Encoder::base16().lowercase();
+
+        assert_eq!(enc.encode_par(&value), enc.encode_bytes(&value));
+    }
+
+    // bases without blocks are encoded on the current thread
+    #[test]
+    fn test2() {
+        let value = input(1000);
+        let enc = Encoder::base91();
+
+        assert_eq!(enc.encode_par(&value), enc.encode_bytes(&value));
+    }
+
+    // a value that is made of whole chunks, the last one of them holds no padding
+    #[test]
+    fn test3() {
+        let value = input(65_535 * 3);
+        let enc = Encoder::base64();
+        let encoded = enc.encode_par(&value);
+
+        assert_eq!(encoded, enc.encode_bytes(&value));
+        assert_eq!(
+            makura::Decoder::decode_to_bytes_par(encoded, makura::BASE64).unwrap(),
+            value
+        );
+    }
+}
+
+mod decoder {
+    use super::{LEN, input};
+    use makura::{BASE16, BASE32, BASE32Z, BASE45, BASE64, BASE64URL, BASE85, Base};
+    use makura::{DecodeError, Decoder, Encoder};
+
+    #[test]
+    fn test0() {
+        let value = input(LEN);
+        [
+            (Encoder::base64(), BASE64),
+            (Encoder::base64_url(), BASE64URL),
+            (Encoder::base32(), BASE32),
+            (Encoder::base32_z(), BASE32Z),
+            (Encoder::base16(), BASE16),
+            (Encoder::base45(), BASE45),
+            (Encoder::base85(), BASE85),
+        ]
+        .into_iter()
+        .for_each(|(enc, base): (Encoder, Base)| {
+            let encoded = enc.encode_par(&value);
+
+            assert_eq!(Decoder::decode_to_bytes_par(encoded, base).unwrap(), value);
+        });
+    }
+
+    // a bad char in a chunk that is not the first one
+    #[test]
+    fn test1() {
+        let mut encoded = Encoder::base64().encode_par(input(LEN));
+        encoded.replace_range(200_000..200_001, "*");
+
+        assert!(matches!(
+            Decoder::decode_to_bytes_par(encoded, BASE64),
+            Err(DecodeError::BadEncodedString)
+        ));
+    }
+
+    // padding at the end of a chunk that is not the last one
+    #[test]
+    fn test2() {
+        let mut encoded = Encoder::base64().encode_par(input(LEN));
+        encoded.replace_range(65_534..65_536, "==");
+
+        assert!(Decoder::decode_to_bytes_par(encoded, BASE64).is_err());
+    }
+}
@@ -0,0 +1,119 @@
+mod encoder {
+    use makura::percent_encoding::{self, PercentSet};
+
+    #[test]
+    fn test0() {
+        assert_eq!(percent_encoding::encode("", PercentSet::UNRESERVED), "");
+    }
+
+    #[test]
+    fn test1() {
+        let input = "a b/c?d#e~";
+        let output = "a%20b%2Fc%3Fd%23e~";
+
+        assert_eq!(
+            percent_encoding::encode(input, PercentSet::UNRESERVED),
+            output
+        );
+    }
+
+    #[test]
+    fn test2() {
+        let input = "déjà vu/@:+";
+        let output = "d%C3%A9j%C3%A0%20vu%2F@:+";
+
+        assert_eq!(
+            percent_encoding::encode(input, PercentSet::PATH_SEGMENT),
+            output
+        );
+    }
+
+    #[test]
+    fn test3() {
+        let input = "a=1&b=2+3/?";
+        let output = "a%3D1%26b%3D2%2B3/?";
+
+        assert_eq!(percent_encoding::encode(input, PercentSet::QUERY), output);
+    }
+
+    #[test]
+    fn test4() {
+        let input = "user:p@ss!";
+        let output = "user%3Ap%40ss!";
+
+        assert_eq!(
+            percent_encoding::encode(input, PercentSet::USERINFO),
+            output
+        );
+    }
+
+    #[test]
+    fn test5() {
+        let input = "name=Ann Lee & co~";
+        let output = "name%3DAnn+Lee+%26+co%7E";
+
+        assert_eq!(percent_encoding::encode(input, PercentSet::FORM), output);
+    }
+
+    #[test]
+    fn test6() {
+        const PATH: PercentSet = PercentSet::UNRESERVED.keep(b'/').escape(b'~');
+        let input = "/a b/~c";
+        let output = "/a%20b/%7Ec";
+
+        assert_eq!(percent_encoding::encode(input, PATH), output);
+    }
+
+    #[test]
+    fn test7() {
+        let input = [0x00, 0x7f, 0x80, 0xff];
+        let output = "%00%7F%80%FF";
+
+        assert_eq!(
+            percent_encoding::encode(input, PercentSet::UNRESERVED),
+            output
+        );
+    }
+}
+
+mod decoder {
+    use makura::percent_encoding::{self, PercentError, PercentSet};
+
+    #[test]
+    fn test0() {
+        let input = "d%C3%A9j%c3%a0%20vu%2F@:+";
+        let output = "déjà vu/@:+";
+
+        assert_eq!(
+            percent_encoding::decode(input, PercentSet::PATH_SEGMENT).unwrap(),
+            output.as_bytes()
+        );
+    }
+
+    #[test]
+    fn test1() {
+        let input = "name%3DAnn+Lee+%26+co%7E";
+        let output = "name=Ann Lee & co~";
+
+        assert_eq!(
+            percent_encoding::decode(input, PercentSet::FORM).unwrap(),
+            output.as_bytes()
+        );
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(
+            percent_encoding::decode("100%", PercentSet::QUERY),
+            Err(PercentError::BadEscape(3))
+        );
+        assert_eq!(
+            percent_encoding::decode("%4", PercentSet::QUERY),
+            Err(PercentError::BadEscape(0))
+        );
+        assert_eq!(
+            percent_encoding::decode("a%zz", PercentSet::QUERY),
+            Err(PercentError::BadEscape(1))
+        );
+    }
+}
@@ -0,0 +1,108 @@
+mod encoder {
+    use makura::quoted_printable;
+
+    #[test]
+    fn test0() {
+        assert_eq!(quoted_printable::encode(""), "");
+    }
+
+    #[test]
+    fn test1() {
+        let input = "café = coffee";
+        let output = "caf=C3=A9 =3D coffee";
+
+        assert_eq!(quoted_printable::encode(input), output);
+    }
+
+    #[test]
+    fn test2() {
+        // trailing whitespace is escaped, line breaks become crlf
+        let input = "tab\t\nspace \r\nend ";
+        let output = "tab=09\r\nspace=20\r\nend=20";
+
+        assert_eq!(quoted_printable::encode(input), output);
+    }
+
+    #[test]
+    fn test3() {
+        let input = "a".repeat(80);
+        let output = format!("{}=\r\n{}", "a".repeat(75), "a".repeat(5));
+
+        assert_eq!(quoted_printable::encode(input), output);
+    }
+
+    #[test]
+    fn test4() {
+        // a 76 chars line fits without a soft line break
+        let input = "a".repeat(76);
+
+        assert_eq!(quoted_printable::encode(&input), input);
+    }
+
+    #[test]
+    fn test5() {
+        // escapes are never split by a soft line break
+        let input = format!("{}é", "a".repeat(73));
+        let output = format!("{}=\r\n=C3=A9", "a".repeat(73));
+
+        assert_eq!(quoted_printable::encode(input), output);
+    }
+
+    #[test]
+    fn test6() {
+        let input = "a\r\nb";
+        let output = "a=0D=0Ab";
+
+        assert_eq!(quoted_printable::encode_binary(input), output);
+    }
+}
+
+mod decoder {
+    use makura::quoted_printable::{self, QpError};
+
+    #[test]
+    fn test0() {
+        let input = "caf=C3=A9 =3D coffee";
+        let output = "café = coffee";
+
+        assert_eq!(quoted_printable::decode(input).unwrap(), output.as_bytes());
+    }
+
+    #[test]
+    fn test1() {
+        let input = format!("{}=\r\n{}", "a".repeat(75), "a".repeat(5));
+
+        assert_eq!(
+            quoted_printable::decode(&input).unwrap(),
+            "a".repeat(80).as_bytes()
+        );
+    }
+
+    #[test]
+    fn test2() {
+        // lf line breaks, transport whitespace and lowercase escapes
+        let input = "soft =  \nbreak  \ncaf=c3=a9\r\n";
+        let output = "soft break\ncafé\r\n";
+
+        assert_eq!(quoted_printable::decode(input).unwrap(), output.as_bytes());
+    }
+
+    #[test]
+    fn test3() {
+        let input = quoted_printable::encode_binary([0x00, 0x0d, 0x0a, 0xff]);
+
+        assert_eq!(
+            quoted_printable::decode(&input).unwrap(),
+            [0x00, 0x0d, 0x0a, 0xff]
+        );
+    }
+
+    #[test]
+    fn test4() {
+        assert_eq!(
+            quoted_printable::decode("a\nb=ZZ"),
+            Err(QpError::BadEscape(3))
+        );
+        assert_eq!(quoted_printable::decode("ab=4"), Err(QpError::BadEscape(2)));
+    }
+}
@@ -0,0 +1,162 @@
+#![cfg(feature = "simd")]
+// the simd kernels only kick in for values that are long enough for a whole block,
+// the inputs here are long enough for the avx2, sse4.1 and neon blocks and their scalar tails
+
+const B64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+mod common;
+use common::input;
+
+fn reference_base64(value: &[u8]) -> String {
+    value
+        .chunks(3)
+        .flat_map(|chunk| {
+            let n = chunk
+                .iter()
+                .enumerate()
+                .fold(0u32, |n, (idx, b)| n | (*b as u32) << (16 - idx * 8));
+            (0..4).map(move |idx| match idx <= chunk.len() {
+                true => B64[(n >> (18 - idx * 6)) as usize & 63] as char,
+                false => '=',
+            })
+        })
+        .collect()
+}
+
+fn reference_base16(value: &[u8]) -> String {
+    value.iter().map(|b| format!("{b:02X}")).collect()
+}
+
+mod encoder {
+    use super::{input, reference_base16, reference_base64};
+    use makura::Encoder;
+
+    #[test]
+    fn test0() {
+        (0..300).for_each(|len| {
+            let value = input(len);
+
+            assert_eq!(
+                Encoder::base64().encode_bytes(&value),
+                reference_base64(&value)
+            );
+        });
+    }
+
+    #[test]
+    fn test1() {
+        (0..300).for_each(|len| {
+            let value = input(len);
+            let output = reference_base64(&value).replace('+', "-").replace('/', "_");
+
+            assert_eq!(Encoder::base64_url().encode_bytes(&value), output);
+        });
+    }
+
+    #[test]
+    fn test2() {
+        (0..300).for_each(|len| {
+            let value = input(len);
+
+            assert_eq!(
+                Encoder::base16().encode_bytes(&value),
+                reference_base16(&value)
+            );
+        });
+    }
+}
+
+mod decoder {
+    use super::{input, reference_base16, reference_base64};
+    use makura::{BASE16, BASE64, BASE64URL, DecodeError, Decoder};
+
+    #[test]
+    fn test0() {
+        (0..300).for_each(|len| {
+            let value = input(len);
+
+            assert_eq!(
+                Decoder::decode_to_bytes(reference_base64(&value), BASE64).unwrap(),
+                value
+            );
+        });
+    }
+
+    #[test]
+    fn test1() {
+        (0..300).for_each(|len| {
+            let value = input(len);
+            let encoded = reference_base64(&value).replace('+', "-").replace('/', "_");
+
+            assert_eq!(Decoder::decode_to_bytes(encoded, BASE64URL).unwrap(), value);
+        });
+    }
+
+    #[test]
+    fn test2() {
+        (0..300).for_each(|len| {
+            let value = input(len);
+            let encoded = reference_base16(&value);
+
+            assert_eq!(Decoder::decode_to_bytes(&encoded, BASE16).unwrap(), value);
+            assert_eq!(
+                Decoder::decode_to_bytes(encoded.to_lowercase(), BASE16).unwrap(),
+                value
+            );
+        });
+    }
+
+    // values that end with zero bytes, which the simd blocks and the scalar tail both hold
+    #[test]
+    fn test_trailing_zeros() {
+        (0..300).for_each(|len| {
+            let mut value = input(len);
+            value.extend([0; 5]);
+
+            assert_eq!(
+                Decoder::decode_to_bytes(reference_base64(&value), BASE64).unwrap(),
+                value
+            );
+            assert_eq!(
+                Decoder::decode_to_bytes(reference_base16(&value), BASE16).unwrap(),
+                value
+            );
+        });
+    }
+
+    // a bad char in the middle of a simd block
+    #[test]
+    fn test3() {
+        let mut encoded = reference_base64(&input(120));
+        encoded.replace_range(50..51, "*");
+
+        assert!(matches!(
+            Decoder::decode_to_bytes(encoded, BASE64),
+            Err(DecodeError::BadEncodedString)
+        ));
+    }
+
+    #[test]
+    fn test4() {
+        let mut encoded = reference_base16(&input(120));
+        encoded.replace_range(70..71, "g");
+
+        assert!(matches!(
+            Decoder::decode_to_bytes(encoded, BASE16),
+            Err(DecodeError::BadEncodedString)
+        ));
+    }
+
+    // a base 64 url char in a base 64 value and the other way around
+    #[test]
+    fn test5() {
+        let mut encoded = reference_base64(&input(120));
+        encoded.replace_range(10..11, "-");
+
+        assert!(Decoder::decode_to_bytes(&encoded, BASE64).is_err());
+
+        encoded.replace_range(10..11, "/");
+
+        assert!(Decoder::decode_to_bytes(&encoded, BASE64URL).is_err());
+    }
+}
@@ -0,0 +1,121 @@
+mod encoder {
+    use makura::uuencode::{self, Flavor, UuFile};
+
+    #[test]
+    fn test0() {
+        let input = UuFile::new("cat.txt", 0o644, "");
+        let output = "begin 644 cat.txt\n`\nend\n";
+
+        assert_eq!(uuencode::encode(&input, Flavor::Uu), output);
+    }
+
+    #[test]
+    fn test1() {
+        let input = UuFile::new("cat.txt", 0o644, "Cat");
+        let output = "begin 644 cat.txt\n#0V%T\n`\nend\n";
+
+        assert_eq!(uuencode::encode(&input, Flavor::Uu), output);
+    }
+
+    #[test]
+    fn test2() {
+        let input = UuFile::new(
+            "fox.txt",
+            0o600,
+            "The quick brown fox jumps over the lazy dog, twice over.",
+        );
+        let output = "begin 600 fox.txt\n\
+            M5&AE(\'%U:6-K(&)R;W=N(&9O>\"!J=6UP<R!O=F5R(\'1H92!L87IY(&1O9RP@\n\
+            +='=I8V4@;W9E<BX`\n\
+            `\n\
+            end\n";
+
+        assert_eq!(uuencode::encode(&input, Flavor::Uu), output);
+    }
+
+    #[test]
+    fn test_xx() {
+        let input = UuFile::new("cat.txt", 0o755, "Cat");
+        let output = "begin 755 cat.txt\n1Eq3o\n+\nend\n";
+
+        assert_eq!(uuencode::encode(&input, Flavor::Xx), output);
+    }
+}
+
+mod decoder {
+    use makura::uuencode::{self, Flavor, UuError, UuFile};
+
+    #[test]
+    fn test0() {
+        let input = UuFile::new("cat.txt", 0o644, "Cat");
+        let output = "begin 644 cat.txt\n#0V%T\n`\nend\n";
+
+        assert_eq!(uuencode::decode(output, Flavor::Uu).unwrap(), input);
+    }
+
+    #[test]
+    fn test1() {
+        let input = UuFile::new(
+            "fox and dog.txt",
+            0o600,
+            "The quick brown fox jumps over the lazy dog, twice over.",
+        );
+        // crlf line endings, mail headers before the begin line
+        // and the old ' ' zero chars that got stripped off the end of the line
+        let output = "Subject: fox\r\n\r\nbegin 600 fox and dog.txt\r\n\
+            M5&AE(\'%U:6-K(&)R;W=N(&9O>\"!J=6UP<R!O=F5R(\'1H92!L87IY(&1O9RP@\r\n\
+            +='=I8V4@;W9E<BX\r\n\
+            \x20\r\n\
+            end\r\n";
+
+        assert_eq!(uuencode::decode(output, Flavor::Uu).unwrap(), input);
+    }
+
+    #[test]
+    fn test_xx() {
+        let input = UuFile::new("cat.txt", 0o755, "Cat");
+        let output = "begin 755 cat.txt\n1Eq3o\n+\nend\n";
+
+        assert_eq!(uuencode::decode(output, Flavor::Xx).unwrap(), input);
+    }
+
+    #[test]
+    fn test_missing_begin() {
+        let output = "#0V%T\n`\nend\n";
+
+        assert_eq!(
+            uuencode::decode(output, Flavor::Uu),
+            Err(UuError::MissingBegin)
+        );
+    }
+
+    #[test]
+    fn test_bad_begin() {
+        let output = "begin 9x9 cat.txt\n#0V%T\n`\nend\n";
+
+        assert_eq!(
+            uuencode::decode(output, Flavor::Uu),
+            Err(UuError::BadBeginLine("begin 9x9 cat.txt".into()))
+        );
+    }
+
+    #[test]
+    fn test_missing_end() {
+        let output = "begin 644 cat.txt\n#0V%T\n`\n";
+
+        assert_eq!(
+            uuencode::decode(output, Flavor::Uu),
+            Err(UuError::MissingEnd)
+        );
+    }
+
+    #[test]
+    fn test_invalid_char() {
+        let output = "begin 755 cat.txt\n1Eq3o!\n+\nend\n";
+
+        assert_eq!(
+            uuencode::decode(output, Flavor::Xx),
+            Err(UuError::InvalidChar { ch: '!', line: 2 })
+        );
+    }
+}
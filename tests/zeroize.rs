@@ -0,0 +1,78 @@
+#![cfg(feature = "zeroize")]
+
+mod decoder {
+    use makura::{BASE64, BASE85Z, DecodeError, Decoder, Encoder};
+
+    #[test]
+    fn test0() {
+        let value = (0..=255).collect::<Vec<u8>>();
+        [
+            Encoder::base64(),
+            Encoder::base64_url(),
+            Encoder::base64_y64(),
+            Encoder::base45(),
+            Encoder::base32(),
+            Encoder::base32_z(),
+            Encoder::base16(),
+            Encoder::base36(),
+            Encoder::base58(),
+            Encoder::base62(),
+            Encoder::base85(),
+            Encoder::base85_ascii(),
+            Encoder::base85_z(),
+            Encoder::base91(),
+        ]
+        .into_iter()
+        .for_each(|enc| {
+            // z85 only encodes whole chunks of 4 bytes
+            (0..value.len())
+                .step_by(7)
+                .filter(|len| *enc.base() != BASE85Z || len % 4 == 0)
+                .for_each(|len| {
+                    let encoded = enc.encode_bytes(&value[..len]);
+                    let decoded = Decoder::decode_secret(&encoded, *enc.base()).unwrap();
+
+                    assert_eq!(*decoded, &value[..len], "{:?} {len}", enc.base());
+                    // the output was never reallocated, no copy of it was left behind
+                    assert_eq!(
+                        decoded.capacity(),
+                        encoded.len() + 4,
+                        "{:?} {len}",
+                        enc.base()
+                    );
+                })
+        });
+    }
+
+    #[test]
+    fn test1() {
+        assert!(matches!(
+            Decoder::decode_secret("Zm9v*g==", BASE64),
+            Err(DecodeError::BadEncodedString)
+        ));
+    }
+
+    // the ascii85 zero groups grow the value before it gets decoded
+    #[test]
+    fn test2() {
+        let decoded = Decoder::decode_secret("<~zz!!~>", makura::BASE85ASCII).unwrap();
+
+        assert_eq!(*decoded, [0; 9]);
+
+        let encoded = format!("<~{}~>", "z".repeat(100));
+        let decoded = Decoder::decode_secret(&encoded, makura::BASE85ASCII).unwrap();
+
+        assert_eq!(*decoded, [0; 400]);
+        assert_eq!(decoded.capacity(), encoded.len() + 300 + 4);
+    }
+
+    // every leading zero digit of a big integer base decodes into a whole zero byte
+    #[test]
+    fn test3() {
+        let value = [[0; 40].as_slice(), &[1, 2, 3]].concat();
+        let encoded = Encoder::base58().encode_bytes(&value);
+        let decoded = Decoder::decode_secret(&encoded, makura::BASE58).unwrap();
+
+        assert_eq!(*decoded, value);
+    }
+}